@@ -1,8 +1,35 @@
-use crate::level_state::LevelState;
+use crate::{
+    component::{
+        bomb::Bomb,
+        collectible::key::Inventory,
+        floor::{self, hole::hole_at},
+        object::glue,
+        wall::breakable,
+    },
+    direction::Direction,
+    level_state::{
+        positioning::movement::{object_layer, object_pos, Bumped},
+        ItemId, LevelState, ObjectId,
+    },
+};
 use enum_dispatch::enum_dispatch;
+use std::collections::VecDeque;
 
+pub mod activate;
+pub mod collect;
+pub mod convey;
+pub mod cycle_controller;
+pub mod enemy_turn;
+pub mod explode;
+pub mod fall;
+pub mod pull;
 pub mod push;
-// pub mod willing_move;
+pub mod rotate;
+pub mod slide;
+pub mod stick;
+pub mod teleport;
+pub mod wait;
+pub mod willing_move;
 
 pub struct ActionResult {
     pub further_actions: Vec<ActionEnum>,
@@ -18,14 +45,19 @@ impl Default for ActionResult {
 
 #[enum_dispatch]
 pub trait Action: Into<ActionEnum> {
-    fn apply(&self, level_state: &LevelState) -> ActionResult;
+    fn apply(&self, level_state: &mut LevelState) -> ActionResult;
+
+    /// Whether the end of turn effects run after this action, checked before it is applied.
+    fn passes_time(&self, _level_state: &LevelState) -> bool {
+        true
+    }
 }
 
 #[derive(Clone)]
 pub struct NoAction;
 
 impl Action for NoAction {
-    fn apply(&self, _: &LevelState) -> ActionResult {
+    fn apply(&self, _: &mut LevelState) -> ActionResult {
         ActionResult::default()
     }
 }
@@ -33,5 +65,147 @@ impl Action for NoAction {
 #[enum_dispatch(Action)]
 pub enum ActionEnum {
     NoAction(NoAction),
-    // WillingMove(willing_move::WillingMove),
+    Activate(activate::Activate),
+    Collect(collect::Collect),
+    Convey(convey::Convey),
+    CycleController(cycle_controller::CycleController),
+    EnemyTurn(enemy_turn::EnemyTurn),
+    Explode(explode::Explode),
+    Fall(fall::Fall),
+    Pull(pull::Pull),
+    Rotate(rotate::Rotate),
+    Slide(slide::Slide),
+    Stick(stick::Stick),
+    Teleport(teleport::Teleport),
+    Wait(wait::Wait),
+    WillingMove(willing_move::WillingMove),
+}
+
+/// Most actions [`resolve`] applies for a single action. Reactions can feed into each other
+/// forever, like two teleporter pairs on ice that lead into one another.
+pub const MAX_ACTIONS: usize = 4096;
+
+/// Applies the action and every further action it produces, breadth first.
+/// Stops after [`MAX_ACTIONS`], dropping whatever is left.
+pub fn resolve(level_state: &mut LevelState, action: ActionEnum) {
+    let mut queue = VecDeque::from([action]);
+    for _ in 0..MAX_ACTIONS {
+        let Some(action) = queue.pop_front() else {
+            return;
+        };
+        queue.extend(action.apply(level_state).further_actions);
+    }
+}
+
+/// Reactions to objects that just moved in `direction`, the ones in the front first.
+pub fn moved(level_state: &LevelState, objects: &[ObjectId], direction: Direction) -> ActionResult {
+    let mut result = ActionResult::default();
+
+    for &object in objects {
+        if teleport::entrance(level_state, object).is_some() {
+            result
+                .further_actions
+                .push(teleport::Teleport { object, direction }.into());
+        } else {
+            result
+                .further_actions
+                .extend(arrived(level_state, &[object], direction).further_actions);
+        }
+    }
+
+    result
+}
+
+/// Reactions to a blocked move, whichever action made it. `movers` are the objects that moved
+/// on their own, every other object was pushed or carried along.
+///
+/// Walls that block the move are hit once, see [`breakable::hit`].
+/// Bombs that are pushed or carried into a wall explode.
+pub fn bumped(
+    level_state: &mut LevelState,
+    bumped: &[Bumped],
+    movers: &[ObjectId],
+) -> ActionResult {
+    let mut result = ActionResult::default();
+
+    let mut hit = Vec::new();
+    for bumped in bumped {
+        if let ItemId::Wall(wall) = bumped.into {
+            if !hit.contains(&wall) {
+                hit.push(wall);
+                breakable::hit(level_state, wall);
+            }
+        }
+    }
+
+    for bumped in bumped {
+        let initiator = bumped.initiator;
+        if matches!(bumped.into, ItemId::Wall(_))
+            && !movers.contains(&initiator)
+            && level_state.world().entity(initiator.0).contains::<Bomb>()
+        {
+            result
+                .further_actions
+                .push(explode::Explode(initiator).into());
+        }
+    }
+
+    result
+}
+
+/// Reactions to objects that ended up on a new cell, either by moving in `direction` or by
+/// teleporting. Unlike [`moved`], doesn't teleport them again.
+pub fn arrived(
+    level_state: &LevelState,
+    objects: &[ObjectId],
+    direction: Direction,
+) -> ActionResult {
+    let mut result = ActionResult::default();
+    let mut sliding = Vec::new();
+
+    for &object in objects {
+        let pos = object_pos(level_state, object);
+
+        // Objects on top of carriers don't touch the floor
+        if object_layer(level_state, object) > 0 {
+            continue;
+        }
+
+        if hole_at(level_state, pos).is_some() {
+            result.further_actions.push(fall::Fall { object }.into());
+            continue;
+        }
+
+        if level_state.world().entity(object.0).contains::<Inventory>() {
+            if let Some(collectible) = level_state.spatial_index().get_collectible(pos) {
+                result.further_actions.push(
+                    collect::Collect {
+                        object,
+                        collectible,
+                    }
+                    .into(),
+                );
+            }
+        }
+
+        if let Some(floor) = level_state.spatial_index().get_floor(pos) {
+            if floor::OnActivated::of(level_state.world().entity(floor.0)).is_some() {
+                result
+                    .further_actions
+                    .push(activate::Activate(ItemId::Floor(floor)).into());
+            }
+        }
+
+        result.further_actions.push(stick::Stick(object).into());
+
+        // Glued objects slide together, once
+        if slide::on_ice(level_state, object) && !sliding.contains(&object) {
+            sliding.extend(glue::group(level_state, object));
+            result
+                .further_actions
+                .push(slide::Slide { object, direction }.into());
+        }
+    }
+
+    result
 }
@@ -0,0 +1,38 @@
+use super::{Action, ActionResult};
+use crate::{
+    component::{collectible, floor, object, wall},
+    level_state::{ItemId, LevelState},
+};
+
+/// Calls the `OnActivated` callback of the item, if it has one.
+#[derive(Clone)]
+pub struct Activate(pub ItemId);
+
+impl Action for Activate {
+    fn apply(&self, level_state: &mut LevelState) -> ActionResult {
+        let entity = self.0.entity();
+        if level_state.is_destroyed(entity) {
+            return ActionResult::default();
+        }
+
+        let world = level_state.world();
+        match self.0 {
+            ItemId::Collectible(id) => match world.get::<collectible::OnActivated>(entity) {
+                Some(&on_activated) => (on_activated.callback)(id, level_state),
+                None => ActionResult::default(),
+            },
+            ItemId::Floor(id) => match floor::OnActivated::of(world.entity(entity)) {
+                Some(on_activated) => (on_activated.callback)(id, level_state),
+                None => ActionResult::default(),
+            },
+            ItemId::Object(id) => match world.get::<object::OnActivated>(entity) {
+                Some(&on_activated) => (on_activated.callback)(id, level_state),
+                None => ActionResult::default(),
+            },
+            ItemId::Wall(id) => match world.get::<wall::OnActivated>(entity) {
+                Some(&on_activated) => (on_activated.callback)(id, level_state),
+                None => ActionResult::default(),
+            },
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use super::{Action, ActionResult};
+use crate::{
+    component::{collectible::key::Key, Group},
+    level_state::{
+        state_change::{change_keys::ChangeKeys, destroy::Destroy},
+        CollectibleId, ItemId, LevelState, ObjectId,
+    },
+};
+
+/// Object picks up the collectible it stands on.
+#[derive(Clone)]
+pub struct Collect {
+    pub object: ObjectId,
+    pub collectible: CollectibleId,
+}
+
+impl Action for Collect {
+    fn apply(&self, level_state: &mut LevelState) -> ActionResult {
+        if level_state.is_destroyed(self.collectible.0) {
+            return ActionResult::default();
+        }
+
+        let collectible = level_state.world().entity(self.collectible.0);
+        let key_group = collectible
+            .contains::<Key>()
+            .then(|| collectible.get::<Group>().copied())
+            .flatten();
+
+        level_state.state_change(Destroy(ItemId::Collectible(self.collectible)).into());
+        level_state.interrupt();
+        if let Some(group) = key_group {
+            level_state.state_change(
+                ChangeKeys {
+                    object: self.object,
+                    group,
+                    amount: 1,
+                }
+                .into(),
+            );
+        }
+
+        ActionResult::default()
+    }
+}
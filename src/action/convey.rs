@@ -0,0 +1,37 @@
+use super::{Action, ActionResult};
+use crate::{
+    direction::Direction,
+    level_state::{
+        positioning::movement::{can_move, move_target, CanMove},
+        LevelState, ObjectId,
+    },
+    target::Target,
+};
+
+/// Object being carried by the environment, for example by a
+/// [`Conveyor`](crate::component::floor::conveyor::Conveyor), together with everything glued to
+/// it. Doesn't push.
+#[derive(Clone)]
+pub struct Convey {
+    pub object: ObjectId,
+    pub direction: Direction,
+}
+
+impl Action for Convey {
+    fn apply(&self, level_state: &mut LevelState) -> ActionResult {
+        if level_state.is_destroyed(self.object.0) {
+            return ActionResult::default();
+        }
+
+        let target = Target::Glued(self.object);
+        match can_move(level_state, &target, self.direction) {
+            CanMove::Can => {
+                // CORRECTNESS: `can_move` returns `CanMove::Can`
+                let moved = move_target(level_state, &target, self.direction);
+                super::moved(level_state, &moved, self.direction)
+            }
+            CanMove::BumpedInto(bumped) => super::bumped(level_state, &bumped, &[]),
+            _ => ActionResult::default(),
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use super::{Action, ActionResult};
+use crate::{
+    component::object::{Controllable, Object},
+    level_state::{state_change::switch_controller::SwitchController, LevelState, ObjectId},
+};
+use bevy::ecs::{entity::Entity, query::With};
+
+/// Passes control to the next [`Controllable`] object.
+/// Objects are ordered by their [`ObjectId`], destroyed ones are skipped.
+#[derive(Clone)]
+pub struct CycleController;
+
+impl Action for CycleController {
+    fn apply(&self, level_state: &mut LevelState) -> ActionResult {
+        let world = level_state.world();
+        // `Disabled` entities are filtered out of queries by default
+        let mut controllables = world
+            .try_query_filtered::<Entity, (With<Object>, With<Controllable>)>()
+            .expect("`Object` and `Controllable` should be registered")
+            .iter(world)
+            .map(ObjectId)
+            .collect::<Vec<_>>();
+        controllables.sort_unstable();
+
+        let next = match level_state.active_controller() {
+            Some(current) => controllables
+                .iter()
+                .copied()
+                .find(|&object| object > current)
+                .or(controllables.first().copied()),
+            None => controllables.first().copied(),
+        };
+
+        if next != level_state.active_controller() {
+            level_state.state_change(SwitchController(next).into());
+        }
+
+        ActionResult::default()
+    }
+
+    fn passes_time(&self, _: &LevelState) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        game_loop::perform_turn,
+        level_state::{state_change::destroy::Destroy, testing, ItemId},
+    };
+    use bevy::math::IVec2;
+
+    fn controllables(level_state: &mut LevelState, amount: i32) -> Vec<ObjectId> {
+        (0..amount)
+            .map(|x| ObjectId(level_state.spawn((Object::new(IVec2::new(x, 0)), Controllable))))
+            .collect()
+    }
+
+    #[test]
+    fn cycles_in_id_order_and_wraps_around() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let objects = controllables(&mut level_state, 3);
+
+        let mut active = Vec::new();
+        for _ in 0..4 {
+            perform_turn(&mut level_state, CycleController.into());
+            active.push(level_state.active_controller().unwrap());
+        }
+        assert_eq!(active, [objects[0], objects[1], objects[2], objects[0]]);
+    }
+
+    #[test]
+    fn skips_destroyed_controllables() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let objects = controllables(&mut level_state, 3);
+
+        perform_turn(&mut level_state, CycleController.into());
+        level_state.next_batch(true);
+        level_state.state_change(Destroy(ItemId::Object(objects[1])).into());
+
+        perform_turn(&mut level_state, CycleController.into());
+        assert_eq!(level_state.active_controller(), Some(objects[2]));
+    }
+
+    #[test]
+    fn undo_restores_the_active_controller() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let objects = controllables(&mut level_state, 2);
+
+        perform_turn(&mut level_state, CycleController.into());
+        perform_turn(&mut level_state, CycleController.into());
+        assert_eq!(level_state.active_controller(), Some(objects[1]));
+
+        level_state.undo_batch();
+        assert_eq!(level_state.active_controller(), Some(objects[0]));
+        level_state.undo_batch();
+        assert_eq!(level_state.active_controller(), None);
+    }
+
+    #[test]
+    fn cycling_to_the_same_controller_takes_no_undo() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let objects = controllables(&mut level_state, 1);
+
+        perform_turn(&mut level_state, CycleController.into());
+        perform_turn(&mut level_state, CycleController.into());
+        assert_eq!(level_state.active_controller(), Some(objects[0]));
+
+        // Undoes the first cycle, not the second one that changed nothing
+        level_state.undo_batch();
+        assert_eq!(level_state.active_controller(), None);
+        assert_eq!(level_state.undos(), 1);
+    }
+}
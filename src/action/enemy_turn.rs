@@ -0,0 +1,137 @@
+use super::{Action, ActionResult};
+use crate::{
+    component::object::{
+        enemy::{Enemy, PatrolStep},
+        facing,
+    },
+    direction::Direction,
+    level_state::{
+        positioning::movement::{can_move, move_target, object_pos, CanMove},
+        state_change::{set_facing::SetFacing, set_patrol_step::SetPatrolStep},
+        LevelState, ObjectId,
+    },
+    target::Target,
+};
+use bevy::math::IVec2;
+
+/// One move of the [`Enemy`], together with everything glued to it.
+/// Enemies with a [`Facing`](facing::Facing) turn the way they move.
+#[derive(Clone)]
+pub struct EnemyTurn(pub ObjectId);
+
+impl Action for EnemyTurn {
+    fn apply(&self, level_state: &mut LevelState) -> ActionResult {
+        let object = self.0;
+        if level_state.is_destroyed(object.0) {
+            return ActionResult::default();
+        }
+        let Some(enemy) = level_state.world().get::<Enemy>(object.0).cloned() else {
+            return ActionResult::default();
+        };
+
+        match enemy {
+            Enemy::Patrol(path) => {
+                if path.is_empty() {
+                    return ActionResult::default();
+                }
+                let step = level_state
+                    .world()
+                    .get::<PatrolStep>(object.0)
+                    .copied()
+                    .unwrap_or_default()
+                    .0;
+
+                if !can_step(level_state, object, path[step % path.len()]) {
+                    return bump(level_state, object, path[step % path.len()]);
+                }
+                level_state.state_change(
+                    SetPatrolStep {
+                        object,
+                        step: (step + 1) % path.len(),
+                    }
+                    .into(),
+                );
+                step_to(level_state, object, path[step % path.len()])
+            }
+            Enemy::Bounce => {
+                let Some(direction) = facing::facing(level_state, object) else {
+                    return ActionResult::default();
+                };
+
+                if can_step(level_state, object, direction) {
+                    return step_to(level_state, object, direction);
+                }
+
+                let mut result = bump(level_state, object, direction);
+                if can_step(level_state, object, -direction) {
+                    let stepped = step_to(level_state, object, -direction);
+                    result.further_actions.extend(stepped.further_actions);
+                } else {
+                    turn(level_state, object, -direction);
+                }
+                result
+            }
+            Enemy::Follow => {
+                let Some(controller) = level_state.active_controller() else {
+                    return ActionResult::default();
+                };
+                let offset = object_pos(level_state, controller) - object_pos(level_state, object);
+
+                let mut directions = Direction::ALL
+                    .into_iter()
+                    .filter(|&direction| IVec2::from(direction).dot(offset) > 0)
+                    .collect::<Vec<_>>();
+                // Stable, so ties keep the order of `Direction::ALL`
+                directions.sort_by_key(|&direction| -IVec2::from(direction).dot(offset).abs());
+
+                match directions
+                    .iter()
+                    .find(|&&direction| can_step(level_state, object, direction))
+                {
+                    Some(&direction) => step_to(level_state, object, direction),
+                    // Only the way it wanted to go most counts as blocked
+                    None => match directions.first() {
+                        Some(&direction) => bump(level_state, object, direction),
+                        None => ActionResult::default(),
+                    },
+                }
+            }
+        }
+    }
+}
+
+fn can_step(level_state: &LevelState, object: ObjectId, direction: Direction) -> bool {
+    matches!(
+        can_move(level_state, &Target::Glued(object), direction),
+        CanMove::Can
+    )
+}
+
+/// CORRECTNESS: `can_step` should return `true`.
+fn step_to(level_state: &mut LevelState, object: ObjectId, direction: Direction) -> ActionResult {
+    turn(level_state, object, direction);
+    let moved = move_target(level_state, &Target::Glued(object), direction);
+    super::moved(level_state, &moved, direction)
+}
+
+/// Reactions to the enemy being blocked in the direction, see [`bumped`](super::bumped).
+fn bump(level_state: &mut LevelState, object: ObjectId, direction: Direction) -> ActionResult {
+    let target = Target::Glued(object);
+    let CanMove::BumpedInto(bumped) = can_move(level_state, &target, direction) else {
+        return ActionResult::default();
+    };
+    let movers = target.fitting_objects(level_state);
+    super::bumped(level_state, &bumped, &movers)
+}
+
+fn turn(level_state: &mut LevelState, object: ObjectId, direction: Direction) {
+    if facing::facing(level_state, object).is_some_and(|facing| facing != direction) {
+        level_state.state_change(
+            SetFacing {
+                object,
+                facing: direction,
+            }
+            .into(),
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use super::{Action, ActionResult};
+use crate::{
+    component::{
+        bomb::{Bomb, Destructible},
+        object,
+        wall::breakable::Breakable,
+    },
+    direction::Direction,
+    level_state::{
+        positioning::movement::object_pos, state_change::destroy::Destroy, ItemId, LevelState,
+        ObjectId,
+    },
+};
+use bevy::math::IVec2;
+
+/// Destroys the [`Bomb`] and every [`Destructible`] item or [`Breakable`] wall in its blast.
+/// Other bombs in the blast explode in turn. Everything is destroyed within the current turn,
+/// so a single undo brings it all back.
+#[derive(Clone)]
+pub struct Explode(pub ObjectId);
+
+impl Action for Explode {
+    fn apply(&self, level_state: &mut LevelState) -> ActionResult {
+        let bomb = self.0;
+        if level_state.is_destroyed(bomb.0) {
+            return ActionResult::default();
+        }
+        let Some(&Bomb { radius }) = level_state.world().get::<Bomb>(bomb.0) else {
+            return ActionResult::default();
+        };
+
+        let pos = object_pos(level_state, bomb);
+        object::destroy(level_state, bomb);
+
+        let mut items = Vec::new();
+        let radius = radius as i32;
+        for y in -radius..=radius {
+            for x in -radius..=radius {
+                let cell = pos + IVec2::new(x, y);
+                let spatial_index = level_state.spatial_index();
+
+                let objects = spatial_index
+                    .get_stack(cell)
+                    .into_iter()
+                    .map(ItemId::Object);
+                let collectible = spatial_index.get_collectible(cell).map(ItemId::Collectible);
+                let walls = Direction::ALL
+                    .into_iter()
+                    .filter_map(|direction| spatial_index.get_wall(cell, direction))
+                    .map(ItemId::Wall);
+
+                for item in objects.chain(collectible).chain(walls) {
+                    if !items.contains(&item) {
+                        items.push(item);
+                    }
+                }
+            }
+        }
+
+        let mut further_actions = Vec::new();
+        for item in items {
+            let entity = level_state.world().entity(item.entity());
+            match item {
+                ItemId::Object(object) if entity.contains::<Bomb>() => {
+                    further_actions.push(Explode(object).into());
+                }
+                ItemId::Object(object) if entity.contains::<Destructible>() => {
+                    object::destroy(level_state, object);
+                }
+                _ if entity.contains::<Destructible>() || entity.contains::<Breakable>() => {
+                    level_state.state_change(Destroy(item).into());
+                }
+                _ => (),
+            }
+        }
+
+        ActionResult { further_actions }
+    }
+}
@@ -0,0 +1,91 @@
+use super::{Action, ActionResult};
+use crate::{
+    component::{
+        floor::{
+            hole::{filled_hole, hole_at},
+            Floor,
+        },
+        object,
+    },
+    level_state::{
+        positioning::{movement::object_pos, Positioning},
+        state_change::{destroy::Destroy, spawn::Spawn},
+        ItemId, LevelState, ObjectId,
+    },
+};
+
+/// Object falls into the [`Hole`](crate::component::floor::hole::Hole) it stands on.
+#[derive(Clone)]
+pub struct Fall {
+    pub object: ObjectId,
+}
+
+impl Action for Fall {
+    fn apply(&self, level_state: &mut LevelState) -> ActionResult {
+        if level_state.is_destroyed(self.object.0) {
+            return ActionResult::default();
+        }
+
+        let pos = object_pos(level_state, self.object);
+        let Some((floor, hole)) = hole_at(level_state, pos) else {
+            return ActionResult::default();
+        };
+        object::destroy(level_state, self.object);
+        if hole.fills {
+            level_state.state_change(Destroy(ItemId::Floor(floor)).into());
+            level_state
+                .state_change(Spawn(filled_hole, Positioning::Floor(Floor::new(pos))).into());
+        }
+
+        ActionResult::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        action::willing_move::WillingMove,
+        component::{
+            floor::hole::{FilledHole, Hole},
+            object::{NeedsWalkableFloor, Pushable},
+        },
+        direction::Direction,
+        game_loop::perform_turn,
+        level_state::{positioning::Object, testing},
+        target::Target,
+    };
+    use bevy::math::IVec2;
+
+    #[test]
+    fn pushed_object_fills_the_hole_until_undone() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        for x in [0, 1, 3] {
+            level_state.spawn(Floor::new(IVec2::new(x, 0)));
+        }
+        level_state.spawn((Floor::new(IVec2::new(2, 0)), Hole { fills: true }));
+        let pusher =
+            ObjectId(level_state.spawn((Object::new(IVec2::ZERO), NeedsWalkableFloor::default())));
+        let object = ObjectId(level_state.spawn((Object::new(IVec2::new(1, 0)), Pushable)));
+
+        let action = WillingMove {
+            target: Target::Glued(pusher),
+            direction: Direction::Right,
+        };
+        perform_turn(&mut level_state, action.into());
+        assert!(level_state.is_destroyed(object.0));
+        assert_eq!(object_pos(&level_state, pusher), IVec2::new(1, 0));
+        assert!(hole_at(&level_state, IVec2::new(2, 0)).is_none());
+        let floor = level_state
+            .spatial_index()
+            .get_floor(IVec2::new(2, 0))
+            .unwrap();
+        assert!(level_state.world().entity(floor.0).contains::<FilledHole>());
+
+        level_state.undo_batch();
+        assert!(!level_state.is_destroyed(object.0));
+        assert_eq!(object_pos(&level_state, object), IVec2::new(1, 0));
+        assert!(hole_at(&level_state, IVec2::new(2, 0)).is_some());
+    }
+}
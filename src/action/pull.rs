@@ -0,0 +1,175 @@
+use super::{push::push_target, Action, ActionResult};
+use crate::{
+    component::object::{glue, Pullable},
+    direction::Direction,
+    level_state::{
+        positioning::movement::{can_move, move_target, object_cells, object_layer, CanMove},
+        LevelState, ObjectId,
+    },
+    target::Target,
+};
+
+/// Move that drags the [`Pullable`] objects right behind the target one cell along.
+/// Pulled objects pull the objects behind them in turn, so whole chains follow.
+/// Objects are pulled only if the target actually moved, and pulled objects never push:
+/// a link that can't follow stops the rest of the chain.
+#[derive(Clone)]
+pub struct Pull {
+    pub target: Target,
+    pub direction: Direction,
+}
+
+impl Action for Pull {
+    fn apply(&self, level_state: &mut LevelState) -> ActionResult {
+        let (target, can_move) = push_target(level_state, self.target.clone(), self.direction);
+        match can_move {
+            CanMove::Can => (),
+            CanMove::BumpedInto(bumped) => {
+                let movers = self.target.fitting_objects(level_state);
+                return super::bumped(level_state, &bumped, &movers);
+            }
+            _ => return ActionResult::default(),
+        }
+
+        let links = chain(level_state, &target, self.direction);
+
+        // CORRECTNESS: `can_move` returns `CanMove::Can`
+        let mut moved = move_target(level_state, &target, self.direction);
+
+        // Every link moves into the cells the one in front of it just left
+        let mut blocked = ActionResult::default();
+        for link in links {
+            let link = Target::Glued(link);
+            match can_move(level_state, &link, self.direction) {
+                CanMove::Can => moved.extend(move_target(level_state, &link, self.direction)),
+                CanMove::BumpedInto(bumped) => {
+                    blocked = super::bumped(level_state, &bumped, &[]);
+                    break;
+                }
+                _ => break,
+            }
+        }
+
+        let mut result = super::moved(level_state, &moved, self.direction);
+        result.further_actions.extend(blocked.further_actions);
+        result
+    }
+}
+
+/// Pulled objects, the ones closest to the target first. Objects glued to a link move with it
+/// and are represented by it.
+fn chain(level_state: &LevelState, target: &Target, direction: Direction) -> Vec<ObjectId> {
+    let mut front = target.fitting_objects(level_state);
+    let mut taken = front.clone();
+    let mut links = Vec::new();
+
+    loop {
+        let mut next = Vec::new();
+        for object in behind(level_state, &front, direction) {
+            if taken.contains(&object) {
+                continue;
+            }
+            let group = glue::group(level_state, object);
+            taken.extend(&group);
+            next.extend(group);
+            links.push(object);
+        }
+
+        if next.is_empty() {
+            return links;
+        }
+        front = next;
+    }
+}
+
+/// [`Pullable`] objects on the ground right behind the objects, that aren't one of them.
+fn behind(level_state: &LevelState, objects: &[ObjectId], direction: Direction) -> Vec<ObjectId> {
+    let mut behind = Vec::new();
+
+    for &member in objects {
+        if object_layer(level_state, member) > 0 {
+            continue;
+        }
+        for cell in object_cells(level_state, member) {
+            let Some(object) = level_state.spatial_index().get_object((-direction) + cell) else {
+                continue;
+            };
+            if !objects.contains(&object)
+                && !behind.contains(&object)
+                && level_state.world().entity(object.0).contains::<Pullable>()
+            {
+                behind.push(object);
+            }
+        }
+    }
+
+    behind
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        action,
+        component::object::NeedsWalkableFloor,
+        level_state::{
+            positioning::{movement::object_pos, Floor, Object},
+            testing,
+        },
+    };
+    use bevy::math::IVec2;
+
+    #[test]
+    fn chain_follows_in_the_same_step() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        for x in 0..4 {
+            level_state.spawn(Floor::new(IVec2::new(x, 0)));
+        }
+        let puller = ObjectId(level_state.spawn(Object::new(IVec2::new(2, 0))));
+        let first = ObjectId(level_state.spawn((Object::new(IVec2::new(1, 0)), Pullable)));
+        let second = ObjectId(level_state.spawn((Object::new(IVec2::new(0, 0)), Pullable)));
+
+        level_state.next_batch(true);
+        action::resolve(
+            &mut level_state,
+            Pull {
+                target: Target::Glued(puller),
+                direction: Direction::Right,
+            }
+            .into(),
+        );
+        assert_eq!(object_pos(&level_state, puller), IVec2::new(3, 0));
+        assert_eq!(object_pos(&level_state, first), IVec2::new(2, 0));
+        assert_eq!(object_pos(&level_state, second), IVec2::new(1, 0));
+
+        level_state.undo_batch();
+        assert_eq!(object_pos(&level_state, puller), IVec2::new(2, 0));
+        assert_eq!(object_pos(&level_state, second), IVec2::new(0, 0));
+    }
+
+    #[test]
+    fn pull_does_not_start_when_blocked() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        for x in 0..2 {
+            level_state.spawn(Floor::new(IVec2::new(x, 0)));
+        }
+        let puller = ObjectId(
+            level_state.spawn((Object::new(IVec2::new(1, 0)), NeedsWalkableFloor::default())),
+        );
+        let pulled = ObjectId(level_state.spawn((Object::new(IVec2::new(0, 0)), Pullable)));
+
+        level_state.next_batch(true);
+        action::resolve(
+            &mut level_state,
+            Pull {
+                target: Target::Glued(puller),
+                direction: Direction::Right,
+            }
+            .into(),
+        );
+        assert_eq!(object_pos(&level_state, puller), IVec2::new(1, 0));
+        assert_eq!(object_pos(&level_state, pulled), IVec2::new(0, 0));
+    }
+}
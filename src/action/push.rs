@@ -1 +1,110 @@
+use crate::{
+    component::object::{glue, Pushable},
+    direction::Direction,
+    level_state::{
+        positioning::movement::{can_move, CanMove},
+        ItemId, LevelState, ObjectId,
+    },
+    target::Target,
+};
 
+/// Grows the target with every [`Pushable`] object it bumps into, and everything glued to the
+/// target, until it either can move or bumps into something that can't be pushed.
+pub fn push_target(
+    level_state: &LevelState,
+    target: Target,
+    direction: Direction,
+) -> (Target, CanMove) {
+    let mut objects = Vec::new();
+    for object in target.fitting_objects(level_state) {
+        add_glued(level_state, &mut objects, object);
+    }
+    let mut target = Target::Objects(objects.clone());
+
+    loop {
+        let can_move = can_move(level_state, &target, direction);
+        let CanMove::BumpedInto(bumped) = &can_move else {
+            return (target, can_move);
+        };
+
+        for bumped in bumped {
+            let ItemId::Object(object) = bumped.into else {
+                return (target, can_move);
+            };
+            if !level_state.world().entity(object.0).contains::<Pushable>() {
+                return (target, can_move);
+            }
+            add_glued(level_state, &mut objects, object);
+        }
+
+        target = Target::Objects(objects.clone());
+    }
+}
+
+fn add_glued(level_state: &LevelState, objects: &mut Vec<ObjectId>, object: ObjectId) {
+    for glued in glue::group(level_state, object) {
+        if !objects.contains(&glued) {
+            objects.push(glued);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        action::willing_move::WillingMove,
+        component::{
+            object::{Pushable, Shape},
+            wall::{Wall, WallAlignment},
+        },
+        direction::Direction,
+        game_loop::perform_turn,
+        level_state::{
+            positioning::{movement::object_pos, Object},
+            testing, LevelState, ObjectId,
+        },
+        target::Target,
+    };
+    use bevy::math::IVec2;
+
+    fn push_right(level_state: &mut LevelState, pusher: ObjectId) {
+        let action = WillingMove {
+            target: Target::Glued(pusher),
+            direction: Direction::Right,
+        };
+        perform_turn(level_state, action.into());
+    }
+
+    #[test]
+    fn every_cell_of_a_pushed_shape_moves_and_blocks() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let pusher = ObjectId(level_state.spawn(Object::new(IVec2::ZERO)));
+        let shape = ObjectId(level_state.spawn((
+            Object::new(IVec2::new(1, 0)),
+            Shape(vec![IVec2::ZERO, IVec2::Y]),
+            Pushable,
+        )));
+        // Blocks only the upper cell
+        level_state.spawn(Wall::new(IVec2::new(2, 1), WallAlignment::Right));
+
+        push_right(&mut level_state, pusher);
+        assert_eq!(object_pos(&level_state, shape), IVec2::new(2, 0));
+        let spatial_index = level_state.spatial_index();
+        assert_eq!(spatial_index.get_object(IVec2::new(2, 1)), Some(shape));
+        assert_eq!(spatial_index.get_object(IVec2::new(1, 1)), None);
+
+        push_right(&mut level_state, pusher);
+        assert_eq!(object_pos(&level_state, shape), IVec2::new(2, 0));
+        assert_eq!(object_pos(&level_state, pusher), IVec2::new(1, 0));
+
+        level_state.undo_batch();
+        level_state.undo_batch();
+        assert_eq!(object_pos(&level_state, shape), IVec2::new(1, 0));
+        assert_eq!(
+            level_state.spatial_index().get_object(IVec2::new(1, 1)),
+            Some(shape)
+        );
+        assert_eq!(object_pos(&level_state, pusher), IVec2::ZERO);
+    }
+}
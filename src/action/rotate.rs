@@ -0,0 +1,92 @@
+use super::{Action, ActionResult};
+use crate::{
+    component::object::facing,
+    level_state::{state_change::set_facing::SetFacing, LevelState, ObjectId},
+};
+
+/// Turns the object by a right angle. Objects without a [`Facing`](facing::Facing) don't turn,
+/// and then no time passes either.
+#[derive(Clone)]
+pub struct Rotate {
+    pub object: ObjectId,
+    pub clockwise: bool,
+}
+
+impl Action for Rotate {
+    fn apply(&self, level_state: &mut LevelState) -> ActionResult {
+        if level_state.is_destroyed(self.object.0) {
+            return ActionResult::default();
+        }
+        let Some(direction) = facing::facing(level_state, self.object) else {
+            return ActionResult::default();
+        };
+
+        let facing = if self.clockwise {
+            direction.rotate_clockwise()
+        } else {
+            direction.rotate_counterclockwise()
+        };
+        level_state.state_change(
+            SetFacing {
+                object: self.object,
+                facing,
+            }
+            .into(),
+        );
+
+        ActionResult::default()
+    }
+
+    fn passes_time(&self, level_state: &LevelState) -> bool {
+        !level_state.is_destroyed(self.object.0)
+            && facing::facing(level_state, self.object).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        component::object::facing::Facing,
+        direction::Direction,
+        game_loop::perform_turn,
+        level_state::{positioning::Object, testing},
+    };
+    use bevy::math::IVec2;
+
+    fn rotate(object: ObjectId) -> Rotate {
+        Rotate {
+            object,
+            clockwise: true,
+        }
+    }
+
+    #[test]
+    fn rotation_is_undone() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let object = ObjectId(level_state.spawn((Object::new(IVec2::ZERO), Facing(Direction::Up))));
+
+        assert!(rotate(object).passes_time(&level_state));
+        perform_turn(&mut level_state, rotate(object).into());
+        assert_eq!(facing::facing(&level_state, object), Some(Direction::Right));
+
+        level_state.undo_batch();
+        assert_eq!(facing::facing(&level_state, object), Some(Direction::Up));
+    }
+
+    #[test]
+    fn rotating_without_facing_takes_no_turn() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let object = ObjectId(level_state.spawn(Object::new(IVec2::ZERO)));
+
+        assert!(!rotate(object).passes_time(&level_state));
+        perform_turn(&mut level_state, rotate(object).into());
+        assert_eq!(level_state.moves(), 0);
+
+        // Nothing to undo
+        level_state.undo_batch();
+        assert_eq!(level_state.undos(), 0);
+    }
+}
@@ -0,0 +1,127 @@
+use super::{Action, ActionResult};
+use crate::{
+    component::floor::ice::Ice,
+    direction::Direction,
+    level_state::{
+        positioning::movement::{can_move, move_target, object_pos, CanMove},
+        LevelState, ObjectId,
+    },
+    target::Target,
+};
+
+/// One step of sliding over [`Ice`], together with everything glued to the object.
+/// Stops when the object is blocked or leaves the ice. Sliding objects don't push.
+#[derive(Clone)]
+pub struct Slide {
+    pub object: ObjectId,
+    pub direction: Direction,
+}
+
+impl Action for Slide {
+    fn apply(&self, level_state: &mut LevelState) -> ActionResult {
+        if level_state.is_destroyed(self.object.0) || !on_ice(level_state, self.object) {
+            return ActionResult::default();
+        }
+
+        let target = Target::Glued(self.object);
+        match can_move(level_state, &target, self.direction) {
+            CanMove::Can => {
+                // CORRECTNESS: `can_move` returns `CanMove::Can`
+                let moved = move_target(level_state, &target, self.direction);
+                super::moved(level_state, &moved, self.direction)
+            }
+            CanMove::BumpedInto(bumped) => super::bumped(level_state, &bumped, &[]),
+            _ => ActionResult::default(),
+        }
+    }
+}
+
+pub fn on_ice(level_state: &LevelState, object: ObjectId) -> bool {
+    let pos = object_pos(level_state, object);
+    level_state
+        .spatial_index()
+        .get_floor(pos)
+        .is_some_and(|floor| level_state.world().entity(floor.0).contains::<Ice>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        action::willing_move::WillingMove,
+        component::{
+            object::NeedsWalkableFloor,
+            wall::{Opened, Wall, WallAlignment},
+        },
+        game_loop::perform_turn,
+        level_state::{
+            positioning::{Floor, Object},
+            testing,
+        },
+    };
+    use bevy::math::IVec2;
+
+    /// Floor from `x = 0` to `x = 5` that is icy in the range, and an object that needs floor at
+    /// the start.
+    fn row(level_state: &mut LevelState, ice: std::ops::Range<i32>) -> ObjectId {
+        for x in 0..6 {
+            let floor = level_state.spawn(Floor::new(IVec2::new(x, 0)));
+            if ice.contains(&x) {
+                level_state.world_mut().entity_mut(floor).insert(Ice);
+            }
+        }
+        ObjectId(level_state.spawn((Object::new(IVec2::ZERO), NeedsWalkableFloor::default())))
+    }
+
+    fn step_right(level_state: &mut LevelState, object: ObjectId) {
+        let action = WillingMove {
+            target: Target::Glued(object),
+            direction: Direction::Right,
+        };
+        perform_turn(level_state, action.into());
+    }
+
+    #[test]
+    fn whole_slide_is_undone_at_once() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let object = row(&mut level_state, 1..4);
+
+        step_right(&mut level_state, object);
+        assert_eq!(object_pos(&level_state, object), IVec2::new(4, 0));
+        assert_eq!(level_state.moves(), 1);
+
+        level_state.undo_batch();
+        assert_eq!(object_pos(&level_state, object), IVec2::ZERO);
+    }
+
+    #[test]
+    fn slide_stops_on_floor_without_ice() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let object = row(&mut level_state, 1..2);
+
+        step_right(&mut level_state, object);
+        assert_eq!(object_pos(&level_state, object), IVec2::new(2, 0));
+    }
+
+    #[test]
+    fn slide_stops_at_closed_wall_and_passes_opened_one() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let object = row(&mut level_state, 1..6);
+        let wall = level_state.spawn(Wall::new(IVec2::new(2, 0), WallAlignment::Right));
+
+        step_right(&mut level_state, object);
+        assert_eq!(object_pos(&level_state, object), IVec2::new(2, 0));
+
+        level_state.undo_batch();
+        level_state
+            .world_mut()
+            .entity_mut(wall)
+            .insert(Opened(true));
+        step_right(&mut level_state, object);
+        // Stops at the end of the floor
+        assert_eq!(object_pos(&level_state, object), IVec2::new(5, 0));
+    }
+}
@@ -0,0 +1,18 @@
+use super::{Action, ActionResult};
+use crate::{
+    component::object::glue,
+    level_state::{LevelState, ObjectId},
+};
+
+/// Glues the object to its neighbors if either of them is [`Sticky`](glue::Sticky).
+#[derive(Clone)]
+pub struct Stick(pub ObjectId);
+
+impl Action for Stick {
+    fn apply(&self, level_state: &mut LevelState) -> ActionResult {
+        if !level_state.is_destroyed(self.0 .0) {
+            glue::stick(level_state, self.0);
+        }
+        ActionResult::default()
+    }
+}
@@ -0,0 +1,115 @@
+use super::{Action, ActionResult};
+use crate::{
+    component::floor::{teleporter, Floor},
+    direction::Direction,
+    level_state::{
+        positioning::movement::{departed, fits, object_cells, object_layer, object_pos},
+        state_change::teleport,
+        FloorId, LevelState, ObjectId,
+    },
+};
+
+/// Moves the object from the teleporter it stands on to the partner teleporter.
+/// The object keeps the direction it entered with.
+#[derive(Clone)]
+pub struct Teleport {
+    pub object: ObjectId,
+    pub direction: Direction,
+}
+
+impl Action for Teleport {
+    fn apply(&self, level_state: &mut LevelState) -> ActionResult {
+        if level_state.is_destroyed(self.object.0) {
+            return ActionResult::default();
+        }
+
+        let Some(exit) = entrance(level_state, self.object)
+            .and_then(|floor| teleporter::partner(level_state, floor))
+        else {
+            return ActionResult::default();
+        };
+        let to = level_state
+            .world()
+            .get::<Floor>(exit.0)
+            .expect("`teleporter::partner` should return floor")
+            .pos();
+
+        // Checked by `can_move_entity` before the object entered, but something
+        // could have arrived on the exit since then.
+        if !fits(level_state, self.object, to) {
+            return ActionResult::default();
+        }
+
+        let cells = object_cells(level_state, self.object);
+        level_state.state_change(
+            teleport::Teleport {
+                object: self.object,
+                to,
+            }
+            .into(),
+        );
+        departed(level_state, self.object, cells);
+        level_state.interrupt();
+
+        super::arrived(level_state, &[self.object], self.direction)
+    }
+}
+
+/// Teleporter that the object stands on. Objects on top of carriers don't use teleporters.
+pub fn entrance(level_state: &LevelState, object: ObjectId) -> Option<FloorId> {
+    if object_layer(level_state, object) > 0 {
+        return None;
+    }
+    let floor = level_state
+        .spatial_index()
+        .get_floor(object_pos(level_state, object))?;
+    teleporter::partner(level_state, floor).map(|_| floor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        action::willing_move::WillingMove,
+        component::{
+            floor::{ice::Ice, teleporter::Teleporter},
+            object::NeedsWalkableFloor,
+            Group,
+        },
+        game_loop::perform_turn,
+        level_state::{positioning::Object, testing},
+        target::Target,
+    };
+    use bevy::math::IVec2;
+
+    #[test]
+    fn teleporters_on_ice_that_lead_into_each_other_stop() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        // Blue at the ends and red in between, the object slides out of each exit into the
+        // entrance of the other pair.
+        for (x, group) in [
+            (0, Group::Blue),
+            (1, Group::Red),
+            (4, Group::Red),
+            (5, Group::Blue),
+        ] {
+            level_state.spawn((Floor::new(IVec2::new(x, 0)), Ice, Teleporter, group));
+        }
+        for x in 2..4 {
+            level_state.spawn((Floor::new(IVec2::new(x, 0)), Ice));
+        }
+        let object =
+            ObjectId(level_state.spawn((Object::new(IVec2::ZERO), NeedsWalkableFloor::default())));
+
+        let action = WillingMove {
+            target: Target::Glued(object),
+            direction: Direction::Right,
+        };
+        perform_turn(&mut level_state, action.into());
+        assert_eq!(level_state.moves(), 1);
+
+        level_state.undo_batch();
+        assert_eq!(object_pos(&level_state, object), IVec2::ZERO);
+    }
+}
@@ -0,0 +1,14 @@
+use super::{Action, ActionResult};
+use crate::level_state::LevelState;
+
+/// Skips the turn, so the end of turn effects advance without anything moving.
+/// Like every turn, it is recorded on the undo stack by
+/// [`perform_turn`](crate::game_loop::perform_turn).
+#[derive(Clone)]
+pub struct Wait;
+
+impl Action for Wait {
+    fn apply(&self, _: &mut LevelState) -> ActionResult {
+        ActionResult::default()
+    }
+}
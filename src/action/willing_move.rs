@@ -0,0 +1,51 @@
+use super::{push::push_target, Action, ActionResult};
+use crate::{
+    component::wall::lock,
+    direction::Direction,
+    level_state::{
+        positioning::movement::{move_target, CanMove},
+        ItemId, LevelState,
+    },
+    target::Target,
+};
+
+/// Move that the target makes on its own, pushing whatever is in front of it.
+#[derive(Clone)]
+pub struct WillingMove {
+    pub target: Target,
+    pub direction: Direction,
+}
+
+impl Action for WillingMove {
+    fn apply(&self, level_state: &mut LevelState) -> ActionResult {
+        let (target, can_move) = push_target(level_state, self.target.clone(), self.direction);
+
+        match can_move {
+            CanMove::Can => {
+                // CORRECTNESS: `can_move` returns `CanMove::Can`
+                let moved = move_target(level_state, &target, self.direction);
+                return super::moved(level_state, &moved, self.direction);
+            }
+            CanMove::BumpedInto(bumped) => {
+                let mut unlocked = false;
+                for bumped in &bumped {
+                    if let ItemId::Wall(wall) = bumped.into {
+                        unlocked |= lock::try_unlock(level_state, bumped.initiator, wall);
+                    }
+                }
+                // Walk through the unlocked wall in the same turn
+                if unlocked {
+                    return ActionResult {
+                        further_actions: vec![self.clone().into()],
+                    };
+                }
+
+                let movers = self.target.fitting_objects(level_state);
+                return super::bumped(level_state, &bumped, &movers);
+            }
+            _ => (),
+        }
+
+        ActionResult::default()
+    }
+}
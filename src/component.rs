@@ -1,9 +1,14 @@
 use bevy::prelude::Component;
 use enumset::EnumSetType;
 
+pub mod bomb;
 pub mod collectible;
+pub mod color_barrier;
 pub mod floor;
+pub mod laser;
+pub mod logic;
 pub mod object;
+pub mod objective;
 pub mod wall;
 
 pub struct RegisterComponentsPlugin;
@@ -11,14 +16,19 @@ pub struct RegisterComponentsPlugin;
 impl bevy::app::Plugin for RegisterComponentsPlugin {
     fn build(&self, app: &mut bevy::app::App) {
         app.add_plugins((
+            bomb::RegisterBombComponentsPlugin,
             collectible::RegisterCollectibleComponentsPlugin,
             floor::RegisterFloorComponentsPlugin,
+            laser::RegisterLaserComponentsPlugin,
+            logic::RegisterLogicComponentsPlugin,
             object::RegisterObjectComponentsPlugin,
+            objective::RegisterObjectiveComponentsPlugin,
             wall::RegisterWallComponentsPlugin,
         ));
 
         let world = app.world_mut();
         world.register_component::<Group>();
+        world.register_component::<color_barrier::ColorBarrier>();
     }
 }
 
@@ -0,0 +1,158 @@
+use crate::{
+    action::{explode::Explode, ActionResult},
+    component::object::Object,
+    level_state::{state_change::burn_fuse::BurnFuse, LevelState, ObjectId},
+};
+use bevy::{ecs::entity::Entity, prelude::Component};
+
+pub struct RegisterBombComponentsPlugin;
+
+impl bevy::app::Plugin for RegisterBombComponentsPlugin {
+    fn build(&self, app: &mut bevy::app::App) {
+        let world = app.world_mut();
+        world.register_component::<Bomb>();
+        world.register_component::<Fuse>();
+        world.register_component::<Destructible>();
+    }
+}
+
+/// Object that [`Explode`]s when it runs out of [`Fuse`], or when it is pushed or carried into a
+/// wall.
+/// The blast reaches every cell at most `radius` cells away along both axes.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bomb {
+    pub radius: u32,
+}
+
+/// Turns left until the bomb explodes. Changed only through [`BurnFuse`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fuse {
+    pub(crate) turns_left: u32,
+}
+
+impl Fuse {
+    #[inline]
+    pub fn new(turns: u32) -> Self {
+        Self { turns_left: turns }
+    }
+
+    #[inline]
+    pub fn turns_left(&self) -> u32 {
+        self.turns_left
+    }
+}
+
+/// Object, collectible or wall that explosions destroy.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Destructible;
+
+/// Burns every fuse down by a turn and explodes the bombs whose fuse ran out.
+pub fn end_of_turn(level_state: &mut LevelState) -> ActionResult {
+    let world = level_state.world();
+    let mut fuses = world
+        .try_query::<(Entity, &Object, &Fuse)>()
+        .expect("`Object` and `Fuse` should be registered")
+        .iter(world)
+        .filter(|(.., fuse)| fuse.turns_left > 0)
+        .map(|(entity, _, fuse)| (ObjectId(entity), fuse.turns_left))
+        .collect::<Vec<_>>();
+    fuses.sort_unstable();
+
+    let mut further_actions = Vec::new();
+    for (object, turns_left) in fuses {
+        level_state.state_change(BurnFuse { object, turns: -1 }.into());
+        if turns_left == 1 {
+            further_actions.push(Explode(object).into());
+        }
+    }
+
+    ActionResult { further_actions }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        action::{self, willing_move::WillingMove},
+        component::{
+            object::Pushable,
+            wall::{breakable::Breakable, Wall, WallAlignment},
+        },
+        direction::Direction,
+        game_loop::perform_turn,
+        level_state::{testing, WallId},
+        target::Target,
+    };
+    use bevy::math::IVec2;
+
+    fn turns_left(level_state: &LevelState, bomb: ObjectId) -> u32 {
+        level_state
+            .world()
+            .get::<Fuse>(bomb.0)
+            .unwrap()
+            .turns_left()
+    }
+
+    #[test]
+    fn bomb_explodes_when_fuse_runs_out_until_undone() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let bomb = ObjectId(level_state.spawn((
+            Object::new(IVec2::ZERO),
+            Bomb { radius: 1 },
+            Fuse::new(2),
+        )));
+        let wall = WallId(level_state.spawn((
+            Wall::new(IVec2::new(1, 1), WallAlignment::Right),
+            Breakable::new(3),
+        )));
+        let barrel = ObjectId(level_state.spawn((Object::new(IVec2::new(-1, 0)), Destructible)));
+        let far = ObjectId(level_state.spawn((Object::new(IVec2::new(2, 0)), Destructible)));
+
+        for _ in 0..2 {
+            level_state.next_batch(true);
+            for action in end_of_turn(&mut level_state).further_actions {
+                action::resolve(&mut level_state, action);
+            }
+        }
+        assert_eq!(turns_left(&level_state, bomb), 0);
+        assert!(level_state.is_destroyed(bomb.0));
+        assert!(level_state.is_destroyed(wall.0));
+        assert!(level_state.is_destroyed(barrel.0));
+        assert!(!level_state.is_destroyed(far.0));
+
+        level_state.undo_batch();
+        assert_eq!(turns_left(&level_state, bomb), 1);
+        assert!(!level_state.is_destroyed(bomb.0));
+        assert!(!level_state.is_destroyed(wall.0));
+        assert!(!level_state.is_destroyed(barrel.0));
+        assert_eq!(
+            level_state.spatial_index().get_object(IVec2::ZERO),
+            Some(bomb)
+        );
+
+        level_state.undo_batch();
+        assert_eq!(turns_left(&level_state, bomb), 2);
+    }
+
+    #[test]
+    fn bomb_pushed_into_a_wall_explodes_until_undone() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let pusher = ObjectId(level_state.spawn(Object::new(IVec2::ZERO)));
+        let bomb =
+            ObjectId(level_state.spawn((Object::new(IVec2::X), Bomb { radius: 0 }, Pushable)));
+        level_state.spawn(Wall::new(IVec2::X, WallAlignment::Right));
+
+        let action = WillingMove {
+            target: Target::Glued(pusher),
+            direction: Direction::Right,
+        };
+        perform_turn(&mut level_state, action.into());
+        assert!(level_state.is_destroyed(bomb.0));
+        assert!(!level_state.is_destroyed(pusher.0));
+
+        level_state.undo_batch();
+        assert!(!level_state.is_destroyed(bomb.0));
+    }
+}
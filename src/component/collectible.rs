@@ -1,16 +1,25 @@
+use bevy::prelude::Component;
+
+pub mod key;
+
 pub struct RegisterCollectibleComponentsPlugin;
 
 impl bevy::app::Plugin for RegisterCollectibleComponentsPlugin {
     fn build(&self, app: &mut bevy::app::App) {
         let world = app.world_mut();
         world.register_component::<Collectible>();
+        world.register_component::<OnActivated>();
+        world.register_component::<key::Key>();
+        world.register_component::<key::Inventory>();
     }
 }
 
 pub use crate::level_state::positioning::Collectible;
 use crate::{action::ActionResult, level_state::{CollectibleId, LevelState}};
 
+/// Called by [`Activate`](crate::action::activate::Activate).
+#[derive(Component, Clone, Copy)]
 pub struct OnActivated {
-    callback: fn(CollectibleId, &mut LevelState) -> ActionResult,
+    pub callback: fn(CollectibleId, &mut LevelState) -> ActionResult,
 }
 
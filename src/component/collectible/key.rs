@@ -0,0 +1,21 @@
+use crate::component::Group;
+use bevy::{platform_support::collections::HashMap, prelude::Component};
+
+/// Collectible that goes into the [`Inventory`] of the object that picks it up.
+/// Opens [`Locked`](crate::component::wall::lock::Locked) walls of the same [`Group`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Key;
+
+/// Keys carried by an object. Only objects with an inventory pick up collectibles.
+/// Changed only through [`ChangeKeys`](crate::level_state::state_change::change_keys::ChangeKeys).
+#[derive(Component, Debug, Clone, PartialEq, Eq, Default)]
+pub struct Inventory {
+    pub(crate) keys: HashMap<Group, u32>,
+}
+
+impl Inventory {
+    #[inline]
+    pub fn keys(&self, group: Group) -> u32 {
+        self.keys.get(&group).copied().unwrap_or(0)
+    }
+}
@@ -0,0 +1,87 @@
+use super::{object::Colors, Group};
+use crate::level_state::{ItemId, LevelState, ObjectId};
+use bevy::prelude::Component;
+
+/// Wall or floor that lets through only objects of its own [`Group`].
+/// Objects carry groups with [`Group`] or [`Colors`] components.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ColorBarrier;
+
+/// Whether the item is a color barrier that doesn't let the object through.
+pub fn blocks(level_state: &LevelState, barrier: ItemId, object: ObjectId) -> bool {
+    let barrier = level_state.world().entity(barrier.entity());
+    if !barrier.contains::<ColorBarrier>() {
+        return false;
+    }
+
+    barrier
+        .get::<Group>()
+        .is_none_or(|&group| !Colors::of(level_state, object).contains(group))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        action::willing_move::WillingMove,
+        component::wall::{Wall, WallAlignment},
+        direction::Direction,
+        game_loop::perform_turn,
+        level_state::{
+            positioning::{movement::object_pos, Floor, Object},
+            testing,
+        },
+        target::Target,
+    };
+    use bevy::math::IVec2;
+
+    /// Red barrier wall to the right of the start and blue barrier floor at `x = 2`.
+    fn row(level_state: &mut LevelState, group: Group) -> ObjectId {
+        for x in 0..2 {
+            level_state.spawn(Floor::new(IVec2::new(x, 0)));
+        }
+        level_state.spawn((Floor::new(IVec2::new(2, 0)), ColorBarrier, Group::Blue));
+        level_state.spawn((
+            Wall::new(IVec2::ZERO, WallAlignment::Right),
+            ColorBarrier,
+            Group::Red,
+        ));
+        ObjectId(level_state.spawn((Object::new(IVec2::ZERO), group)))
+    }
+
+    fn step_right(level_state: &mut LevelState, object: ObjectId) {
+        let action = WillingMove {
+            target: Target::Glued(object),
+            direction: Direction::Right,
+        };
+        perform_turn(level_state, action.into());
+    }
+
+    #[test]
+    fn barriers_let_only_their_own_group_through() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let red = row(&mut level_state, Group::Red);
+
+        step_right(&mut level_state, red);
+        assert_eq!(object_pos(&level_state, red), IVec2::new(1, 0));
+        step_right(&mut level_state, red);
+        assert_eq!(object_pos(&level_state, red), IVec2::new(1, 0));
+
+        // The blocked turn still passed
+        level_state.undo_batch();
+        assert_eq!(object_pos(&level_state, red), IVec2::new(1, 0));
+        level_state.undo_batch();
+        assert_eq!(object_pos(&level_state, red), IVec2::ZERO);
+    }
+
+    #[test]
+    fn barrier_wall_stops_other_groups() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let blue = row(&mut level_state, Group::Blue);
+
+        step_right(&mut level_state, blue);
+        assert_eq!(object_pos(&level_state, blue), IVec2::ZERO);
+    }
+}
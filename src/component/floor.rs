@@ -1,16 +1,64 @@
+use bevy::{ecs::world::EntityRef, prelude::Component};
+
+pub mod conveyor;
+pub mod crumbling;
+pub mod goal;
+pub mod hole;
+pub mod ice;
+pub mod lava;
+pub mod pressure_plate;
+pub mod teleporter;
+pub mod water;
+
 pub struct RegisterFloorComponentsPlugin;
 
 impl bevy::app::Plugin for RegisterFloorComponentsPlugin {
     fn build(&self, app: &mut bevy::app::App) {
         let world = app.world_mut();
         world.register_component::<Floor>();
+        world.register_component::<OnActivated>();
+        world.register_component::<Unwalkable>();
+        world.register_component::<conveyor::Conveyor>();
+        world.register_component::<crumbling::Crumbling>();
+        world.register_component::<goal::Goal>();
+        world.register_component::<goal::Exit>();
+        world.register_component::<hole::Hole>();
+        world.register_component::<hole::FilledHole>();
+        world.register_component::<ice::Ice>();
+        world.register_component::<lava::Lava>();
+        world.register_component::<pressure_plate::PressurePlate>();
+        world.register_component::<teleporter::Teleporter>();
+        world.register_component::<water::Water>();
+        world.register_component::<water::Bridge>();
     }
 }
 
 pub use crate::level_state::positioning::Floor;
 use crate::{action::ActionResult, level_state::{FloorId, LevelState}};
 
+/// Objects that need walkable floor can't step on it. [`hole::Hole`]s are unwalkable as well.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Unwalkable;
+
+/// Called by [`Activate`](crate::action::activate::Activate).
+/// [`water::Water`] and [`lava::Lava`] floors have one without the component, see [`Self::of`].
+#[derive(Component, Clone, Copy)]
 pub struct OnActivated {
-    callback: fn(FloorId, &mut LevelState) -> ActionResult,
+    pub callback: fn(FloorId, &mut LevelState) -> ActionResult,
+}
+
+impl OnActivated {
+    /// Callback of the floor, its own component first.
+    pub fn of(floor: EntityRef) -> Option<OnActivated> {
+        if let Some(&on_activated) = floor.get::<OnActivated>() {
+            Some(on_activated)
+        } else if floor.contains::<water::Water>() {
+            Some(water::ON_ACTIVATED)
+        } else if floor.contains::<lava::Lava>() {
+            Some(lava::ON_ACTIVATED)
+        } else {
+            None
+        }
+    }
 }
 
@@ -0,0 +1,96 @@
+use crate::{
+    action::{convey::Convey, ActionResult},
+    component::object::glue,
+    direction::Direction,
+    level_state::{LevelState, ObjectId},
+};
+use bevy::{math::IVec2, prelude::Component};
+
+use super::Floor;
+
+/// Moves the object on it one cell in the direction at the end of every turn.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Conveyor(pub Direction);
+
+pub fn end_of_turn(level_state: &mut LevelState) -> ActionResult {
+    let world = level_state.world();
+    let conveyed = world
+        .try_query::<(&Floor, &Conveyor)>()
+        .expect("`Floor` and `Conveyor` should be registered")
+        .iter(world)
+        .filter_map(|(floor, conveyor)| {
+            let object = level_state.spatial_index().get_object(floor.pos())?;
+            Some((floor.pos(), object, conveyor.0))
+        })
+        .collect::<Vec<_>>();
+
+    drift(level_state, conveyed)
+}
+
+/// [`Convey`]s every object standing on a cell at `pos` in the direction, once.
+/// Also used by currents of [`Water`](super::water::Water).
+pub fn drift(
+    level_state: &LevelState,
+    mut conveyed: Vec<(IVec2, ObjectId, Direction)>,
+) -> ActionResult {
+    // Objects further along their conveyor's direction move first, so lines of conveyed objects
+    // don't block themselves. Ties are broken by id to keep the order deterministic.
+    conveyed.sort_unstable_by_key(|&(pos, object, direction)| {
+        (-pos.dot(IVec2::from(direction)), object)
+    });
+
+    // Objects with a `Shape` and glued objects can stand on several cells,
+    // the first one in the order wins
+    let mut seen = Vec::with_capacity(conveyed.len());
+    conveyed.retain(|&(_, object, _)| {
+        let first = !seen.contains(&object);
+        seen.extend(glue::group(level_state, object));
+        first
+    });
+
+    ActionResult {
+        further_actions: conveyed
+            .into_iter()
+            .map(|(_, object, direction)| Convey { object, direction }.into())
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        action::wait::Wait,
+        game_loop::perform_turn,
+        level_state::{
+            positioning::{movement::object_pos, Object},
+            testing, ObjectId,
+        },
+    };
+
+    #[test]
+    fn line_of_objects_moves_at_the_end_of_the_turn() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        for x in 0..2 {
+            level_state.spawn((Floor::new(IVec2::new(x, 0)), Conveyor(Direction::Right)));
+        }
+        level_state.spawn(Floor::new(IVec2::new(2, 0)));
+        let back = ObjectId(level_state.spawn(Object::new(IVec2::new(0, 0))));
+        let front = ObjectId(level_state.spawn(Object::new(IVec2::new(1, 0))));
+
+        perform_turn(&mut level_state, Wait.into());
+        assert_eq!(object_pos(&level_state, back), IVec2::new(1, 0));
+        assert_eq!(object_pos(&level_state, front), IVec2::new(2, 0));
+
+        // Blocked by the object that left the conveyors
+        perform_turn(&mut level_state, Wait.into());
+        assert_eq!(object_pos(&level_state, back), IVec2::new(1, 0));
+        assert_eq!(object_pos(&level_state, front), IVec2::new(2, 0));
+
+        level_state.undo_batch();
+        level_state.undo_batch();
+        assert_eq!(object_pos(&level_state, back), IVec2::new(0, 0));
+        assert_eq!(object_pos(&level_state, front), IVec2::new(1, 0));
+    }
+}
@@ -0,0 +1,102 @@
+use crate::level_state::{
+    state_change::{crumble::Crumble, destroy::Destroy},
+    ItemId, LevelState,
+};
+use bevy::{math::IVec2, prelude::Component};
+
+/// Floor that breaks after objects step off it `departures_left` times, leaving no floor behind.
+/// Changed only through [`Crumble`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Crumbling {
+    pub(crate) departures_left: u32,
+}
+
+impl Crumbling {
+    #[inline]
+    pub fn new(departures: u32) -> Self {
+        Self {
+            departures_left: departures,
+        }
+    }
+
+    #[inline]
+    pub fn departures_left(&self) -> u32 {
+        self.departures_left
+    }
+}
+
+/// Should be called when an object on the ground leaves the cell.
+pub fn departed(level_state: &mut LevelState, pos: IVec2) {
+    let Some(floor) = level_state.spatial_index().get_floor(pos) else {
+        return;
+    };
+    let Some(&crumbling) = level_state.world().get::<Crumbling>(floor.0) else {
+        return;
+    };
+    // `Crumbling::new(0)` floors don't break, same as breakable walls without hit points
+    if crumbling.departures_left == 0 {
+        return;
+    }
+
+    level_state.state_change(
+        Crumble {
+            floor,
+            departures: -1,
+        }
+        .into(),
+    );
+
+    if crumbling.departures_left == 1 {
+        level_state.state_change(Destroy(ItemId::Floor(floor)).into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level_state::{positioning::Floor, testing, FloorId};
+
+    fn departures_left(level_state: &LevelState, floor: FloorId) -> u32 {
+        level_state
+            .world()
+            .get::<Crumbling>(floor.0)
+            .unwrap()
+            .departures_left()
+    }
+
+    #[test]
+    fn undo_restores_the_floor() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let floor = FloorId(level_state.spawn((Floor::new(IVec2::ZERO), Crumbling::new(2))));
+
+        level_state.next_batch(true);
+        departed(&mut level_state, IVec2::ZERO);
+        level_state.next_batch(true);
+        departed(&mut level_state, IVec2::ZERO);
+        assert!(level_state.is_destroyed(floor.0));
+        assert_eq!(level_state.spatial_index().get_floor(IVec2::ZERO), None);
+
+        level_state.undo_batch();
+        assert_eq!(departures_left(&level_state, floor), 1);
+        assert_eq!(
+            level_state.spatial_index().get_floor(IVec2::ZERO),
+            Some(floor)
+        );
+
+        level_state.undo_batch();
+        assert_eq!(departures_left(&level_state, floor), 2);
+    }
+
+    #[test]
+    fn floor_without_departures_left_does_not_break() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let floor = FloorId(level_state.spawn((Floor::new(IVec2::ZERO), Crumbling::new(0))));
+
+        level_state.next_batch(true);
+        departed(&mut level_state, IVec2::ZERO);
+        assert_eq!(departures_left(&level_state, floor), 0);
+        assert!(!level_state.is_destroyed(floor.0));
+    }
+}
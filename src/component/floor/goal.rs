@@ -0,0 +1,12 @@
+use bevy::prelude::Component;
+
+/// Floor that needs an object of the same [`Group`](crate::component::Group) on it for
+/// [`Objective::GoalsFilled`](crate::component::objective::Objective::GoalsFilled).
+/// Goal without a group accepts any object.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Goal;
+
+/// Floor that a controllable object has to reach for
+/// [`Objective::ExitReached`](crate::component::objective::Objective::ExitReached).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Exit;
@@ -0,0 +1,25 @@
+use crate::level_state::{FloorId, LevelState};
+use bevy::{ecs::world::EntityWorldMut, math::IVec2, prelude::Component};
+
+/// Objects that need walkable floor can't step into it. Any other object that ends up on it falls
+/// in and is destroyed. See [`crate::action::fall::Fall`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Hole {
+    /// The hole is replaced with [`FilledHole`] floor after swallowing an object.
+    pub fills: bool,
+}
+
+/// Walkable floor left after a [`Hole`] swallowed an object.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FilledHole;
+
+pub fn filled_hole(entity: &mut EntityWorldMut) {
+    entity.insert(FilledHole);
+}
+
+/// Hole under the position, if there is one.
+pub fn hole_at(level_state: &LevelState, pos: IVec2) -> Option<(FloorId, Hole)> {
+    let floor = level_state.spatial_index().get_floor(pos)?;
+    let hole = level_state.world().get::<Hole>(floor.0)?;
+    Some((floor, *hole))
+}
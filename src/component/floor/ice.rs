@@ -0,0 +1,6 @@
+use bevy::prelude::Component;
+
+/// Objects that enter this floor keep sliding in the same direction.
+/// See [`crate::action::slide::Slide`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Ice;
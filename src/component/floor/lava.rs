@@ -0,0 +1,28 @@
+use super::{Floor, OnActivated};
+use crate::{
+    action::ActionResult,
+    component::object,
+    level_state::{FloorId, LevelState},
+};
+use bevy::prelude::Component;
+
+/// Destroys every object that enters it.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Lava;
+
+pub const ON_ACTIVATED: OnActivated = OnActivated {
+    callback: on_activated,
+};
+
+fn on_activated(floor: FloorId, level_state: &mut LevelState) -> ActionResult {
+    let pos = level_state
+        .world()
+        .get::<Floor>(floor.0)
+        .expect("`FloorId` should point to an entity with `Floor` component")
+        .pos();
+    if let Some(object) = level_state.spatial_index().get_object(pos) {
+        object::destroy(level_state, object);
+    }
+
+    ActionResult::default()
+}
@@ -0,0 +1,24 @@
+use crate::level_state::{FloorId, LevelState};
+use bevy::prelude::Component;
+
+use super::Floor;
+
+/// Pressed while objects rest on it. Drives [`Door`](crate::component::wall::door::Door)s of
+/// the same [`Group`](crate::component::Group).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PressurePlate;
+
+/// Amount of objects resting on the plate, including the ones stacked on top of each other.
+pub fn pressed_by(level_state: &LevelState, plate: FloorId) -> usize {
+    let pos = level_state
+        .world()
+        .get::<Floor>(plate.0)
+        .expect("`FloorId` should point to an entity with `Floor` component")
+        .pos();
+    level_state.spatial_index().get_stack(pos).len()
+}
+
+#[inline]
+pub fn is_pressed(level_state: &LevelState, plate: FloorId) -> bool {
+    pressed_by(level_state, plate) > 0
+}
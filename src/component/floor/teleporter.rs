@@ -0,0 +1,73 @@
+use crate::{
+    component::Group,
+    level_state::{FloorId, LevelState},
+};
+use bevy::{ecs::entity::Entity, prelude::Component};
+
+use super::Floor;
+
+/// Objects that enter it reappear on the other teleporter of the same [`Group`].
+///
+/// Teleporters are linked in pairs, a group with any other number of them doesn't teleport.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Teleporter;
+
+/// The other teleporter of the same [`Group`], if the floor is a teleporter that has one.
+/// Groups with more than two teleporters don't teleport at all.
+pub fn partner(level_state: &LevelState, floor: FloorId) -> Option<FloorId> {
+    let world = level_state.world();
+    let entity = world.entity(floor.0);
+    if !entity.contains::<Teleporter>() {
+        return None;
+    }
+    let group = *entity.get::<Group>()?;
+
+    // `Disabled` entities are filtered out of queries by default
+    let others = world
+        .try_query::<(Entity, &Group, &Floor, &Teleporter)>()
+        .expect("`Floor` and `Teleporter` should be registered")
+        .iter(world)
+        .filter(|&(other, &other_group, _, _)| other != floor.0 && other_group == group)
+        .map(|(other, ..)| FloorId(other))
+        .collect::<Vec<_>>();
+
+    match others[..] {
+        [other] => Some(other),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level_state::testing;
+    use bevy::math::IVec2;
+
+    fn spawn(level_state: &mut LevelState, x: i32, group: Group) -> FloorId {
+        FloorId(level_state.spawn((Floor::new(IVec2::new(x, 0)), Teleporter, group)))
+    }
+
+    #[test]
+    fn teleporters_of_a_group_are_partners() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let red = spawn(&mut level_state, 0, Group::Red);
+        let other_red = spawn(&mut level_state, 1, Group::Red);
+        let blue = spawn(&mut level_state, 2, Group::Blue);
+
+        assert_eq!(partner(&level_state, red), Some(other_red));
+        assert_eq!(partner(&level_state, other_red), Some(red));
+        assert_eq!(partner(&level_state, blue), None);
+    }
+
+    #[test]
+    fn groups_of_three_have_no_partners() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let teleporters = [0, 1, 2].map(|x| spawn(&mut level_state, x, Group::Red));
+
+        for teleporter in teleporters {
+            assert_eq!(partner(&level_state, teleporter), None);
+        }
+    }
+}
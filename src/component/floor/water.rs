@@ -0,0 +1,170 @@
+use super::{conveyor, Floor, OnActivated};
+use crate::{
+    action::ActionResult,
+    component::object::{self, Floats, Sinks},
+    direction::Direction,
+    level_state::{
+        positioning::Positioning,
+        state_change::{destroy::Destroy, spawn::Spawn},
+        FloorId, ItemId, LevelState,
+    },
+};
+use bevy::{ecs::world::EntityWorldMut, prelude::Component};
+
+/// Objects that [`Float`](Floats) stay on it and drift with the current at the end of every turn.
+/// Objects that [`Sink`](Sinks) fill deep water and turn it into a [`Bridge`].
+/// Anything else drowns in deep water.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Water {
+    pub deep: bool,
+    pub current: Option<Direction>,
+}
+
+/// Walkable floor left after an object sank in deep [`Water`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Bridge;
+
+pub const ON_ACTIVATED: OnActivated = OnActivated {
+    callback: on_activated,
+};
+
+pub fn bridge(entity: &mut EntityWorldMut) {
+    entity.insert(Bridge);
+}
+
+fn on_activated(floor: FloorId, level_state: &mut LevelState) -> ActionResult {
+    let world = level_state.world();
+    let (Some(&water), Some(&position)) =
+        (world.get::<Water>(floor.0), world.get::<Floor>(floor.0))
+    else {
+        return ActionResult::default();
+    };
+    let Some(object) = level_state.spatial_index().get_object(position.pos()) else {
+        return ActionResult::default();
+    };
+
+    let object_entity = world.entity(object.0);
+    if !water.deep || object_entity.contains::<Floats>() {
+        return ActionResult::default();
+    }
+
+    if object_entity.contains::<Sinks>() {
+        object::destroy(level_state, object);
+        level_state.state_change(Destroy(ItemId::Floor(floor)).into());
+        level_state.state_change(Spawn(bridge, Positioning::Floor(position)).into());
+    } else {
+        object::destroy(level_state, object);
+    }
+
+    ActionResult::default()
+}
+
+/// Floating objects drift with the current, see [`conveyor::drift`].
+pub fn end_of_turn(level_state: &mut LevelState) -> ActionResult {
+    let world = level_state.world();
+    let drifting = world
+        .try_query::<(&Floor, &Water)>()
+        .expect("`Floor` and `Water` should be registered")
+        .iter(world)
+        .filter_map(|(floor, water)| {
+            let object = level_state.spatial_index().get_object(floor.pos())?;
+            let floats = world.entity(object.0).contains::<Floats>();
+            Some((floor.pos(), object, water.current.filter(|_| floats)?))
+        })
+        .collect::<Vec<_>>();
+
+    conveyor::drift(level_state, drifting)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        action::{self, willing_move::WillingMove},
+        component::{floor::lava::Lava, object::Controllable},
+        event::LevelLost,
+        game_loop::perform_turn,
+        level_state::{
+            positioning::{movement::object_pos, Object},
+            testing, ObjectId,
+        },
+        target::Target,
+    };
+    use bevy::math::IVec2;
+
+    fn step_right(level_state: &mut LevelState, object: ObjectId) {
+        let action = WillingMove {
+            target: Target::Glued(object),
+            direction: Direction::Right,
+        };
+        perform_turn(level_state, action.into());
+    }
+
+    #[test]
+    fn sinking_controllable_loses_the_level() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let water = Water {
+            deep: true,
+            current: None,
+        };
+        level_state.spawn(Floor::new(IVec2::ZERO));
+        let floor = FloorId(level_state.spawn((Floor::new(IVec2::X), water)));
+        let object = ObjectId(level_state.spawn((Object::new(IVec2::ZERO), Sinks, Controllable)));
+
+        step_right(&mut level_state, object);
+        assert!(level_state.is_destroyed(object.0));
+        assert!(level_state.is_destroyed(floor.0));
+        assert_eq!(testing::sent::<LevelLost>(&level_state), 1);
+
+        let bridge = level_state.spatial_index().get_floor(IVec2::X).unwrap();
+        assert!(level_state.world().entity(bridge.0).contains::<Bridge>());
+
+        level_state.undo_batch();
+        assert!(!level_state.is_destroyed(object.0));
+        assert_eq!(object_pos(&level_state, object), IVec2::ZERO);
+        assert_eq!(level_state.spatial_index().get_floor(IVec2::X), Some(floor));
+    }
+
+    #[test]
+    fn lava_destroys_objects_that_enter_it() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        level_state.spawn(Floor::new(IVec2::ZERO));
+        level_state.spawn((Floor::new(IVec2::X), Lava));
+        let object = ObjectId(level_state.spawn((Object::new(IVec2::ZERO), Floats)));
+
+        step_right(&mut level_state, object);
+        assert!(level_state.is_destroyed(object.0));
+
+        level_state.undo_batch();
+        assert!(!level_state.is_destroyed(object.0));
+        assert_eq!(object_pos(&level_state, object), IVec2::ZERO);
+    }
+
+    #[test]
+    fn line_of_floating_objects_drifts_together() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let water = Water {
+            deep: true,
+            current: Some(Direction::Right),
+        };
+        for x in 0..3 {
+            level_state.spawn((Floor::new(IVec2::new(x, 0)), water));
+        }
+        let back = ObjectId(level_state.spawn((Object::new(IVec2::new(0, 0)), Floats)));
+        let front = ObjectId(level_state.spawn((Object::new(IVec2::new(1, 0)), Floats)));
+
+        level_state.next_batch(true);
+        for action in end_of_turn(&mut level_state).further_actions {
+            action::resolve(&mut level_state, action);
+        }
+        assert_eq!(object_pos(&level_state, back), IVec2::new(1, 0));
+        assert_eq!(object_pos(&level_state, front), IVec2::new(2, 0));
+
+        level_state.undo_batch();
+        assert_eq!(object_pos(&level_state, back), IVec2::ZERO);
+        assert_eq!(object_pos(&level_state, front), IVec2::new(1, 0));
+    }
+}
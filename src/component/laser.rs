@@ -0,0 +1,245 @@
+use crate::{
+    action::{activate::Activate, ActionResult},
+    component::{
+        object::{facing, Object},
+        wall::Opened,
+        Group,
+    },
+    direction::Direction,
+    level_state::{positioning::movement::object_pos, ItemId, LevelState, ObjectId},
+};
+use bevy::{
+    ecs::entity::Entity, math::IVec2, platform_support::collections::HashSet, prelude::Component,
+};
+
+pub struct RegisterLaserComponentsPlugin;
+
+impl bevy::app::Plugin for RegisterLaserComponentsPlugin {
+    fn build(&self, app: &mut bevy::app::App) {
+        let world = app.world_mut();
+        world.register_component::<Emitter>();
+        world.register_component::<Mirror>();
+        world.register_component::<Receiver>();
+    }
+}
+
+/// Beams longer than this are cut off, so beams leaving the level end.
+const MAX_BEAM_LENGTH: usize = 256;
+
+/// Object that fires a beam in the direction, or the way it faces if it has a
+/// [`Facing`](facing::Facing). Beams are blocked by walls that aren't [`Opened`]
+/// and by objects other than [`Mirror`]s.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Emitter(pub Direction);
+
+/// Object that turns beams by a right angle.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mirror {
+    /// `/`, turns beams going right up.
+    Slash,
+    /// `\`, turns beams going right down.
+    Backslash,
+}
+
+impl Mirror {
+    /// Orientation of a mirror with the [`Facing`](facing::Facing), where facing up keeps it as is.
+    pub fn turned(self, facing: Option<Direction>) -> Self {
+        match (self, facing) {
+            (Mirror::Slash, Some(Direction::Left | Direction::Right)) => Mirror::Backslash,
+            (Mirror::Backslash, Some(Direction::Left | Direction::Right)) => Mirror::Slash,
+            _ => self,
+        }
+    }
+
+    pub fn reflect(self, direction: Direction) -> Direction {
+        match (self, direction) {
+            (Mirror::Slash, Direction::Right) | (Mirror::Backslash, Direction::Left) => {
+                Direction::Up
+            }
+            (Mirror::Slash, Direction::Left) | (Mirror::Backslash, Direction::Right) => {
+                Direction::Down
+            }
+            (Mirror::Slash, Direction::Up) | (Mirror::Backslash, Direction::Down) => {
+                Direction::Right
+            }
+            (Mirror::Slash, Direction::Down) | (Mirror::Backslash, Direction::Up) => {
+                Direction::Left
+            }
+        }
+    }
+}
+
+/// Object or floor that is lit by beams of [`Emitter`]s of the same [`Group`].
+/// Receiver objects stop the beam, receiver floors let it pass over.
+/// Emitters and receivers without a group match each other.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Receiver;
+
+/// Receivers lit by the emitter's beam, in the order the beam reaches them.
+pub fn trace(level_state: &LevelState, emitter: ObjectId) -> Vec<ItemId> {
+    let (world, spatial_index) = (level_state.world(), level_state.spatial_index());
+    let Some(&Emitter(direction)) = world.get::<Emitter>(emitter.0) else {
+        return Vec::new();
+    };
+    let mut direction = facing::facing(level_state, emitter).unwrap_or(direction);
+    let group = world.get::<Group>(emitter.0);
+    let matches = |entity: Entity| {
+        let entity = world.entity(entity);
+        entity.contains::<Receiver>() && entity.get::<Group>() == group
+    };
+
+    let mut pos = object_pos(level_state, emitter);
+    let mut visited = HashSet::<(IVec2, Direction)>::default();
+    let mut lit = Vec::new();
+
+    for _ in 0..MAX_BEAM_LENGTH {
+        if let Some(wall) = spatial_index.get_wall(pos, direction) {
+            if !world.get::<Opened>(wall.0).is_some_and(|opened| opened.0) {
+                break;
+            }
+        }
+
+        pos = direction + pos;
+        // Mirrors can send the beam around in a loop
+        if !visited.insert((pos, direction)) {
+            break;
+        }
+
+        if let Some(object) = spatial_index.get_object(pos) {
+            if object == emitter {
+                break;
+            }
+            if let Some(&mirror) = world.get::<Mirror>(object.0) {
+                direction = mirror
+                    .turned(facing::facing(level_state, object))
+                    .reflect(direction);
+                continue;
+            }
+            if matches(object.0) {
+                lit.push(ItemId::Object(object));
+            }
+            break;
+        }
+
+        if let Some(floor) = spatial_index.get_floor(pos) {
+            if matches(floor.0) && !lit.contains(&ItemId::Floor(floor)) {
+                lit.push(ItemId::Floor(floor));
+            }
+        }
+    }
+
+    lit
+}
+
+/// Receivers lit by any beam, each once, traced in the order of [`emitters`].
+pub fn lit(level_state: &LevelState) -> Vec<ItemId> {
+    let mut lit = Vec::new();
+    for emitter in emitters(level_state) {
+        for receiver in trace(level_state, emitter) {
+            if !lit.contains(&receiver) {
+                lit.push(receiver);
+            }
+        }
+    }
+    lit
+}
+
+/// Recomputes the beams and activates every lit receiver, once per turn no matter how many beams
+/// reach it.
+pub fn end_of_turn(level_state: &mut LevelState) -> ActionResult {
+    ActionResult {
+        further_actions: lit(level_state)
+            .into_iter()
+            .map(|receiver| Activate(receiver).into())
+            .collect(),
+    }
+}
+
+/// Sorted, so beams are always traced in the same order.
+fn emitters(level_state: &LevelState) -> Vec<ObjectId> {
+    let world = level_state.world();
+    let mut emitters = world
+        .try_query::<(Entity, &Object, &Emitter)>()
+        .expect("`Object` and `Emitter` should be registered")
+        .iter(world)
+        .map(|(entity, ..)| ObjectId(entity))
+        .collect::<Vec<_>>();
+    emitters.sort_unstable();
+    emitters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        component::wall::{Wall, WallAlignment},
+        level_state::{
+            positioning::{movement::move_target, Floor},
+            testing, FloorId,
+        },
+        target::Target,
+    };
+
+    /// Red emitter firing right at a mirror that turns the beam up, past a red receiver floor,
+    /// a blue one, a red receiver object and another red receiver floor behind it.
+    fn level(level_state: &mut LevelState) -> (ObjectId, ObjectId, FloorId, ObjectId) {
+        let emitter = ObjectId(level_state.spawn((
+            Object::new(IVec2::ZERO),
+            Emitter(Direction::Right),
+            Group::Red,
+        )));
+        let mirror = ObjectId(level_state.spawn((Object::new(IVec2::new(2, 0)), Mirror::Slash)));
+        let floor =
+            FloorId(level_state.spawn((Floor::new(IVec2::new(2, 1)), Receiver, Group::Red)));
+        level_state.spawn((Floor::new(IVec2::new(2, 2)), Receiver, Group::Blue));
+        let object =
+            ObjectId(level_state.spawn((Object::new(IVec2::new(2, 3)), Receiver, Group::Red)));
+        level_state.spawn((Floor::new(IVec2::new(2, 4)), Receiver, Group::Red));
+        (emitter, mirror, floor, object)
+    }
+
+    #[test]
+    fn beam_turns_on_mirrors_and_stops_at_receiver_objects() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let (emitter, _, floor, object) = level(&mut level_state);
+
+        assert_eq!(
+            trace(&level_state, emitter),
+            [ItemId::Floor(floor), ItemId::Object(object)]
+        );
+    }
+
+    #[test]
+    fn beam_follows_moved_mirrors_until_undone() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let (emitter, mirror, floor, object) = level(&mut level_state);
+
+        level_state.next_batch(true);
+        move_target(&mut level_state, &Target::Object(mirror), Direction::Down);
+        assert!(trace(&level_state, emitter).is_empty());
+
+        level_state.undo_batch();
+        assert_eq!(
+            trace(&level_state, emitter),
+            [ItemId::Floor(floor), ItemId::Object(object)]
+        );
+    }
+
+    #[test]
+    fn closed_walls_block_the_beam() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let (emitter, ..) = level(&mut level_state);
+        let wall = level_state.spawn(Wall::new(IVec2::new(2, 1), WallAlignment::Up));
+
+        assert_eq!(trace(&level_state, emitter).len(), 1);
+
+        level_state
+            .world_mut()
+            .entity_mut(wall)
+            .insert(Opened(true));
+        assert_eq!(trace(&level_state, emitter).len(), 2);
+    }
+}
@@ -0,0 +1,322 @@
+use std::collections::VecDeque;
+
+use crate::{
+    action::{activate::Activate, ActionResult},
+    component::{
+        collectible::Collectible,
+        floor::{pressure_plate, Floor},
+        laser,
+        object::Object,
+        wall::{Opened, Wall},
+    },
+    level_state::{
+        state_change::{set_gate_state::SetGateState, set_opened::SetOpened},
+        CollectibleId, FloorId, ItemId, LevelState, ObjectId, WallId,
+    },
+};
+use bevy::{ecs::entity::Entity, platform_support::collections::HashMap, prelude::Component};
+
+pub mod wiring;
+
+pub struct RegisterLogicComponentsPlugin;
+
+impl bevy::app::Plugin for RegisterLogicComponentsPlugin {
+    fn build(&self, app: &mut bevy::app::App) {
+        let world = app.world_mut();
+        world.register_component::<Gate>();
+        world.register_component::<GateState>();
+        world.register_component::<Wire>();
+    }
+}
+
+/// Upper bound on passes over the circuit in a turn, so circuits that feed back into
+/// themselves through a [`GateKind::Not`] can't oscillate forever.
+const MAX_PASSES: usize = 64;
+
+/// Entity with a [`Gate`]. Gates don't have a position on the board, levels store them as a
+/// [`Wiring`](wiring::Wiring).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GateId(pub(crate) Entity);
+
+/// Signal that a gate reads.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Input {
+    /// On while the [`PressurePlate`](pressure_plate::PressurePlate) is pressed.
+    Plate(FloorId),
+    /// On while the [`Receiver`](laser::Receiver) is lit.
+    Receiver(ItemId),
+    /// Output of another gate.
+    Gate(GateId),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GateKind {
+    /// On while every input is on. Off without inputs.
+    And,
+    /// On while any input is on.
+    Or,
+    /// On while an odd amount of inputs is on.
+    Xor,
+    /// On while no input is on.
+    Not,
+    /// Flips every turn that any input turns on.
+    Toggle,
+    /// On while any input was on the amount of turns ago.
+    Delay(u32),
+}
+
+/// Combines the inputs into an output, which drives [`Wire`]d items and other gates.
+#[derive(Component, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Gate {
+    pub kind: GateKind,
+    pub inputs: Vec<Input>,
+}
+
+/// Output of the gate and what it remembers of previous turns.
+/// Changed only through [`SetGateState`].
+#[derive(Component, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct GateState {
+    pub(crate) output: bool,
+    /// Whether any input was on last turn, for [`GateKind::Toggle`].
+    pub(crate) previous_input: bool,
+    /// Whether any input was on, for the last turns of [`GateKind::Delay`], the oldest first.
+    pub(crate) history: VecDeque<bool>,
+}
+
+impl GateState {
+    #[inline]
+    pub fn output(&self) -> bool {
+        self.output
+    }
+}
+
+/// Item driven by the gate's output. Walls are [`Opened`] while the output is on,
+/// other items are [`Activate`]d every turn the output is on.
+/// Walls shouldn't be both wired and a [`Door`](crate::component::wall::door::Door).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Wire(pub GateId);
+
+impl GateKind {
+    /// `state` is the state the gate had at the start of the turn.
+    fn output(self, state: &GateState, inputs: &[bool]) -> bool {
+        let any = inputs.iter().any(|&input| input);
+        match self {
+            GateKind::And => !inputs.is_empty() && inputs.iter().all(|&input| input),
+            GateKind::Or => any,
+            GateKind::Xor => inputs.iter().filter(|&&input| input).count() % 2 == 1,
+            GateKind::Not => !any,
+            GateKind::Toggle => state.output ^ (any && !state.previous_input),
+            GateKind::Delay(0) => any,
+            GateKind::Delay(turns) => {
+                state.history.len() == turns as usize && state.history.front() == Some(&true)
+            }
+        }
+    }
+}
+
+/// Evaluates the circuit until no output changes, then drives the wired items.
+///
+/// Plates and receivers are read once, before any gate is evaluated, so the circuit only reacts
+/// to the board as it is at the end of the turn.
+pub fn end_of_turn(level_state: &mut LevelState) -> ActionResult {
+    let world = level_state.world();
+    let mut gates = world
+        .try_query::<(Entity, &Gate, Option<&GateState>)>()
+        .expect("`Gate` should be registered")
+        .iter(world)
+        .map(|(entity, gate, state)| {
+            (
+                GateId(entity),
+                gate.clone(),
+                state.cloned().unwrap_or_default(),
+            )
+        })
+        .collect::<Vec<_>>();
+    gates.sort_unstable_by_key(|&(gate, ..)| gate);
+
+    // Beams are traced once for every receiver input
+    let lit = laser::lit(level_state);
+    let mut sources = HashMap::<Input, bool>::default();
+    for input in gates.iter().flat_map(|(_, gate, _)| &gate.inputs) {
+        let on = match *input {
+            Input::Plate(plate) => pressure_plate::is_pressed(level_state, plate),
+            Input::Receiver(receiver) => lit.contains(&receiver),
+            Input::Gate(_) => continue,
+        };
+        sources.insert(*input, on);
+    }
+
+    let mut outputs = gates
+        .iter()
+        .map(|(gate, _, state)| (*gate, state.output))
+        .collect::<HashMap<_, _>>();
+    let inputs = |gate: &Gate, outputs: &HashMap<GateId, bool>| {
+        gate.inputs
+            .iter()
+            .map(|input| match input {
+                Input::Gate(other) => outputs.get(other).copied().unwrap_or_default(),
+                _ => sources[input],
+            })
+            .collect::<Vec<_>>()
+    };
+
+    for _ in 0..MAX_PASSES {
+        let mut changed = false;
+        for (id, gate, state) in &gates {
+            let output = gate.kind.output(state, &inputs(gate, &outputs));
+            changed |= outputs.insert(*id, output) != Some(output);
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    for (id, gate, state) in &gates {
+        let any = inputs(gate, &outputs).into_iter().any(|input| input);
+        let mut history = state.history.clone();
+        if let GateKind::Delay(turns) = gate.kind {
+            history.push_back(any);
+            while history.len() > turns as usize {
+                history.pop_front();
+            }
+        }
+
+        let new_state = GateState {
+            output: outputs[id],
+            previous_input: any,
+            history,
+        };
+        if new_state != *state {
+            level_state.state_change(
+                SetGateState {
+                    gate: *id,
+                    state: new_state,
+                }
+                .into(),
+            );
+        }
+    }
+
+    drive_wires(level_state, &outputs)
+}
+
+fn drive_wires(level_state: &mut LevelState, outputs: &HashMap<GateId, bool>) -> ActionResult {
+    let world = level_state.world();
+    let mut wires = world
+        .try_query::<(Entity, &Wire)>()
+        .expect("`Wire` should be registered")
+        .iter(world)
+        .map(|(entity, wire)| (entity, outputs.get(&wire.0).copied().unwrap_or_default()))
+        .collect::<Vec<_>>();
+    wires.sort_unstable_by_key(|&(entity, _)| entity);
+
+    let mut further_actions = Vec::new();
+    for (entity, on) in wires {
+        let item = level_state.world().entity(entity);
+        if item.contains::<Wall>() {
+            let opened = item.get::<Opened>().copied().unwrap_or_default();
+            if opened.0 != on {
+                level_state.state_change(
+                    SetOpened {
+                        wall: WallId(entity),
+                        opened: on,
+                    }
+                    .into(),
+                );
+                level_state.interrupt();
+            }
+        } else if on {
+            let item = if item.contains::<Object>() {
+                ItemId::Object(ObjectId(entity))
+            } else if item.contains::<Floor>() {
+                ItemId::Floor(FloorId(entity))
+            } else if item.contains::<Collectible>() {
+                ItemId::Collectible(CollectibleId(entity))
+            } else {
+                continue;
+            };
+            further_actions.push(Activate(item).into());
+        }
+    }
+
+    ActionResult { further_actions }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        component::{floor::pressure_plate::PressurePlate, wall::WallAlignment},
+        level_state::{positioning::Object, testing},
+    };
+    use bevy::math::IVec2;
+
+    fn output(level_state: &LevelState, gate: GateId) -> bool {
+        level_state
+            .world()
+            .get::<GateState>(gate.0)
+            .is_some_and(GateState::output)
+    }
+
+    #[test]
+    fn toggle_drives_the_wall_until_undone() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let plate = FloorId(level_state.spawn((Floor::new(IVec2::ZERO), PressurePlate)));
+        let gate = GateId(level_state.spawn(Gate {
+            kind: GateKind::Toggle,
+            inputs: vec![Input::Plate(plate)],
+        }));
+        let wall =
+            WallId(level_state.spawn((Wall::new(IVec2::new(3, 0), WallAlignment::Up), Wire(gate))));
+        let opened = |level_state: &LevelState| {
+            level_state
+                .world()
+                .get::<Opened>(wall.0)
+                .is_some_and(|opened| opened.0)
+        };
+
+        level_state.next_batch(true);
+        end_of_turn(&mut level_state);
+        assert!(!output(&level_state, gate));
+
+        level_state.spawn(Object::new(IVec2::ZERO));
+        level_state.next_batch(true);
+        end_of_turn(&mut level_state);
+        assert!(output(&level_state, gate));
+        assert!(opened(&level_state));
+
+        // Stays on while the plate is held
+        level_state.next_batch(true);
+        end_of_turn(&mut level_state);
+        assert!(output(&level_state, gate));
+
+        level_state.undo_batch();
+        level_state.undo_batch();
+        assert!(!output(&level_state, gate));
+        assert!(!opened(&level_state));
+    }
+
+    #[test]
+    fn delay_repeats_input_after_the_turns() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let plate = FloorId(level_state.spawn((Floor::new(IVec2::ZERO), PressurePlate)));
+        let gate = GateId(level_state.spawn(Gate {
+            kind: GateKind::Delay(2),
+            inputs: vec![Input::Plate(plate)],
+        }));
+        level_state.spawn(Object::new(IVec2::ZERO));
+
+        let mut outputs = Vec::new();
+        for _ in 0..3 {
+            level_state.next_batch(true);
+            end_of_turn(&mut level_state);
+            outputs.push(output(&level_state, gate));
+        }
+        assert_eq!(outputs, [false, false, true]);
+
+        level_state.undo_batch();
+        assert!(!output(&level_state, gate));
+    }
+}
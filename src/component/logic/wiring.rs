@@ -0,0 +1,264 @@
+use super::{Gate, GateId, GateKind, Input, Wire};
+use crate::level_state::{
+    positioning::{Floor, Positioning},
+    CollectibleId, FloorId, ItemId, ObjectId, WallId,
+};
+use bevy::{
+    ecs::{entity::Entity, world::World},
+    math::IVec2,
+    platform_support::collections::HashMap,
+};
+
+/// [`Gate`]s and [`Wire`]s of a level in the form it is stored in, without entity ids.
+/// Gates refer to each other by their index in `gates`, items are referred to by their
+/// [`Positioning`]. See [`Wiring::load`].
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct Wiring {
+    pub gates: Vec<GateDesc>,
+    /// Index of the gate and the item it drives.
+    pub wires: Vec<(usize, Positioning)>,
+}
+
+/// [`Gate`] of a [`Wiring`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GateDesc {
+    pub kind: GateKind,
+    pub inputs: Vec<InputDesc>,
+}
+
+/// [`Input`] of a [`Wiring`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InputDesc {
+    /// Position of the pressure plate.
+    Plate(IVec2),
+    Receiver(Positioning),
+    /// Index of the other gate.
+    Gate(usize),
+}
+
+/// Reference of a [`Wiring`] that doesn't point to anything.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WiringError {
+    NoItem(Positioning),
+    NoGate(usize),
+}
+
+impl Wiring {
+    /// Spawns the gates and wires the items, which should be spawned already.
+    /// Nothing is spawned if any reference doesn't resolve.
+    pub fn load(&self, world: &mut World) -> Result<Vec<GateId>, WiringError> {
+        let items = world
+            .iter_entities()
+            .filter_map(|entity| Some((Positioning::get(entity)?, entity.id())))
+            .collect::<HashMap<_, _>>();
+        let gates = self
+            .gates
+            .iter()
+            .map(|_| GateId(world.spawn_empty().id()))
+            .collect::<Vec<_>>();
+
+        match self.resolve(&items, &gates) {
+            Ok((resolved, wires)) => {
+                for (gate, resolved) in gates.iter().zip(resolved) {
+                    world.entity_mut(gate.0).insert(resolved);
+                }
+                for (entity, wire) in wires {
+                    world.entity_mut(entity).insert(wire);
+                }
+                Ok(gates)
+            }
+            Err(error) => {
+                for gate in gates {
+                    world.despawn(gate.0);
+                }
+                Err(error)
+            }
+        }
+    }
+
+    /// Wiring of every gate in the world, indexed in the order of their ids.
+    pub fn save(world: &World) -> Self {
+        let mut gates = world
+            .try_query::<(Entity, &Gate)>()
+            .expect("`Gate` should be registered")
+            .iter(world)
+            .map(|(entity, gate)| (GateId(entity), gate.clone()))
+            .collect::<Vec<_>>();
+        gates.sort_unstable_by_key(|&(gate, _)| gate);
+
+        let index = |gate: GateId| {
+            gates
+                .iter()
+                .position(|&(other, _)| other == gate)
+                .expect("`Input::Gate` and `Wire` should point to a gate")
+        };
+        let positioning = |entity: Entity| {
+            Positioning::get(world.entity(entity))
+                .expect("Inputs and wired items should have a position")
+        };
+
+        let mut wires = world
+            .try_query::<(Entity, &Wire)>()
+            .expect("`Wire` should be registered")
+            .iter(world)
+            .map(|(entity, wire)| (entity, index(wire.0)))
+            .collect::<Vec<_>>();
+        wires.sort_unstable_by_key(|&(entity, _)| entity);
+
+        Self {
+            gates: gates
+                .iter()
+                .map(|(_, gate)| GateDesc {
+                    kind: gate.kind,
+                    inputs: gate
+                        .inputs
+                        .iter()
+                        .map(|&input| match input {
+                            Input::Plate(plate) => InputDesc::Plate(
+                                world
+                                    .get::<Floor>(plate.0)
+                                    .expect("`Input::Plate` should point to floor")
+                                    .pos(),
+                            ),
+                            Input::Receiver(receiver) => {
+                                InputDesc::Receiver(positioning(receiver.entity()))
+                            }
+                            Input::Gate(other) => InputDesc::Gate(index(other)),
+                        })
+                        .collect(),
+                })
+                .collect(),
+            wires: wires
+                .into_iter()
+                .map(|(entity, gate)| (gate, positioning(entity)))
+                .collect(),
+        }
+    }
+
+    fn resolve(
+        &self,
+        items: &HashMap<Positioning, Entity>,
+        gates: &[GateId],
+    ) -> Result<(Vec<Gate>, Vec<(Entity, Wire)>), WiringError> {
+        let item = |positioning: Positioning| {
+            items
+                .get(&positioning)
+                .copied()
+                .ok_or(WiringError::NoItem(positioning))
+        };
+        let gate = |index: usize| gates.get(index).copied().ok_or(WiringError::NoGate(index));
+
+        let resolved = self
+            .gates
+            .iter()
+            .map(|desc| {
+                let inputs = desc
+                    .inputs
+                    .iter()
+                    .map(|&input| {
+                        Ok(match input {
+                            InputDesc::Plate(pos) => {
+                                Input::Plate(FloorId(item(Positioning::Floor(Floor::new(pos)))?))
+                            }
+                            InputDesc::Receiver(positioning) => {
+                                Input::Receiver(item_id(positioning, item(positioning)?))
+                            }
+                            InputDesc::Gate(index) => Input::Gate(gate(index)?),
+                        })
+                    })
+                    .collect::<Result<_, WiringError>>()?;
+                Ok(Gate {
+                    kind: desc.kind,
+                    inputs,
+                })
+            })
+            .collect::<Result<_, WiringError>>()?;
+
+        let wires = self
+            .wires
+            .iter()
+            .map(|&(index, positioning)| Ok((item(positioning)?, Wire(gate(index)?))))
+            .collect::<Result<_, WiringError>>()?;
+
+        Ok((resolved, wires))
+    }
+}
+
+fn item_id(positioning: Positioning, entity: Entity) -> ItemId {
+    match positioning {
+        Positioning::Collectible(_) => ItemId::Collectible(CollectibleId(entity)),
+        Positioning::Floor(_) => ItemId::Floor(FloorId(entity)),
+        Positioning::Object(_) => ItemId::Object(ObjectId(entity)),
+        Positioning::Wall(_) => ItemId::Wall(WallId(entity)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        component::{
+            floor::pressure_plate::PressurePlate,
+            laser::Receiver,
+            wall::{Wall, WallAlignment},
+        },
+        level_state::{positioning::Object, testing},
+    };
+
+    fn wiring() -> Wiring {
+        Wiring {
+            gates: vec![
+                GateDesc {
+                    kind: GateKind::And,
+                    inputs: vec![
+                        InputDesc::Plate(IVec2::ZERO),
+                        InputDesc::Receiver(Positioning::Object(Object::new(IVec2::X))),
+                    ],
+                },
+                GateDesc {
+                    kind: GateKind::Not,
+                    inputs: vec![InputDesc::Gate(0)],
+                },
+            ],
+            wires: vec![(
+                1,
+                Positioning::Wall(Wall::new(IVec2::Y, WallAlignment::Right)),
+            )],
+        }
+    }
+
+    #[test]
+    fn loaded_wiring_saves_the_same() {
+        let mut world = testing::world();
+        let plate = world.spawn((Floor::new(IVec2::ZERO), PressurePlate)).id();
+        let receiver = world.spawn((Object::new(IVec2::X), Receiver)).id();
+        let wall = world.spawn(Wall::new(IVec2::Y, WallAlignment::Right)).id();
+
+        let gates = wiring().load(&mut world).unwrap();
+        assert_eq!(
+            world.get::<Gate>(gates[0].0).unwrap().inputs,
+            [
+                Input::Plate(FloorId(plate)),
+                Input::Receiver(ItemId::Object(ObjectId(receiver))),
+            ]
+        );
+        assert_eq!(
+            world.get::<Gate>(gates[1].0).unwrap().inputs,
+            [Input::Gate(gates[0])]
+        );
+        assert_eq!(world.get::<Wire>(wall), Some(&Wire(gates[1])));
+
+        assert_eq!(Wiring::save(&world), wiring());
+    }
+
+    #[test]
+    fn wiring_with_a_missing_item_loads_nothing() {
+        let mut world = testing::world();
+        world.spawn((Floor::new(IVec2::ZERO), PressurePlate));
+        world.spawn(Wall::new(IVec2::Y, WallAlignment::Right));
+
+        let missing = Positioning::Object(Object::new(IVec2::X));
+        assert_eq!(wiring().load(&mut world), Err(WiringError::NoItem(missing)));
+        assert_eq!(Wiring::save(&world), Wiring::default());
+    }
+}
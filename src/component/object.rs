@@ -1,17 +1,49 @@
 use bevy::prelude::Component;
 
+pub mod enemy;
+pub mod facing;
+pub mod glue;
+
 pub struct RegisterObjectComponentsPlugin;
 
 impl bevy::app::Plugin for RegisterObjectComponentsPlugin {
     fn build(&self, app: &mut bevy::app::App) {
         let world = app.world_mut();
         world.register_component::<Object>();
+        world.register_component::<OnActivated>();
+        world.register_component::<Shape>();
+        world.register_component::<Controllable>();
+        world.register_component::<NeedsWalkableFloor>();
+        world.register_component::<Pushable>();
+        world.register_component::<Pullable>();
+        world.register_component::<Colors>();
+        world.register_component::<Carrier>();
+        world.register_component::<Floats>();
+        world.register_component::<Sinks>();
+        world.register_component::<enemy::Enemy>();
+        world.register_component::<enemy::PatrolStep>();
+        world.register_component::<facing::Facing>();
+        world.register_component::<glue::Glued>();
+        world.register_component::<glue::Sticky>();
     }
 }
 
-pub use crate::level_state::positioning::Object;
-use crate::{action::ActionResult, level_state::{LevelState, ObjectId}};
+pub use crate::level_state::positioning::{Object, Shape};
+use crate::{
+    action::ActionResult,
+    component::Group,
+    event::LevelLost,
+    level_state::{
+        positioning::movement::{carry, riders},
+        state_change::destroy::Destroy,
+        ItemId, LevelState, ObjectId,
+    },
+};
+use bevy::math::IVec2;
+use enumset::EnumSet;
 
+/// Object can only move onto floor that isn't [`Unwalkable`](super::floor::Unwalkable).
+/// Objects without it move anywhere, and fall into holes.
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct NeedsWalkableFloor(pub bool);
 
@@ -22,6 +54,123 @@ impl Default for NeedsWalkableFloor {
     }
 }
 
+/// Groups of a multicolored object, on top of its [`Group`] component.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Colors(pub EnumSet<Group>);
+
+impl Colors {
+    /// Every group the object belongs to.
+    pub fn of(level_state: &LevelState, object: ObjectId) -> EnumSet<Group> {
+        let entity = level_state.world().entity(object.0);
+        let colors = entity.get::<Colors>().copied().unwrap_or_default().0;
+        match entity.get::<Group>() {
+            Some(&group) => colors | group,
+            None => colors,
+        }
+    }
+}
+
+/// Object that is moved along when another object moves into it.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Pushable;
+
+/// Object that follows an object moving away from it with [`Pull`](crate::action::pull::Pull).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Pullable;
+
+/// Object that other objects land on instead of bumping into it, while nothing is on top of it.
+/// Objects on top move together with it. Carriers and objects with a [`Shape`] don't land on
+/// other carriers.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Carrier;
+
+/// Object stays on top of deep [`Water`](super::floor::water::Water) and drifts with its current.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Floats;
+
+/// Object fills deep [`Water`](super::floor::water::Water) it enters, turning it into a bridge.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Sinks;
+
+/// Object that the player can take control of. See [`crate::action::cycle_controller`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Controllable;
+
+/// Destroys the object, objects on top of it drop down a layer.
+/// Losing a [`Controllable`] object loses the level.
+pub fn destroy(level_state: &mut LevelState, object: ObjectId) {
+    let controllable = level_state
+        .world()
+        .entity(object.0)
+        .contains::<Controllable>();
+    let riders = riders(level_state, object);
+
+    level_state.state_change(Destroy(ItemId::Object(object)).into());
+
+    // Keep stacks without gaps
+    for rider in riders {
+        carry(level_state, rider, IVec2::ZERO, -1);
+    }
+    level_state.interrupt();
+
+    if controllable {
+        level_state.send_event(LevelLost);
+    }
+}
+
+/// Called by [`Activate`](crate::action::activate::Activate).
+#[derive(Component, Clone, Copy)]
 pub struct OnActivated {
-    callback: fn(ObjectId, &mut LevelState) -> ActionResult,
+    pub callback: fn(ObjectId, &mut LevelState) -> ActionResult,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level_state::{positioning::movement::object_layer, testing};
+
+    #[test]
+    fn riders_of_destroyed_carrier_drop_down() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let carrier = ObjectId(level_state.spawn((Object::new(IVec2::ZERO), Carrier)));
+        let rider = ObjectId(level_state.spawn((
+            Object {
+                pos: IVec2::ZERO,
+                layer: 1,
+            },
+            Carrier,
+        )));
+        let top = ObjectId(level_state.spawn(Object {
+            pos: IVec2::ZERO,
+            layer: 2,
+        }));
+
+        level_state.next_batch(true);
+        destroy(&mut level_state, carrier);
+        assert!(level_state.is_destroyed(carrier.0));
+        assert_eq!(
+            level_state.spatial_index().get_stack(IVec2::ZERO),
+            [rider, top]
+        );
+        assert_eq!(object_layer(&level_state, top), 1);
+        assert_eq!(testing::sent::<LevelLost>(&level_state), 0);
+
+        level_state.undo_batch();
+        assert_eq!(
+            level_state.spatial_index().get_stack(IVec2::ZERO),
+            [carrier, rider, top]
+        );
+    }
+
+    #[test]
+    fn destroying_controllable_loses_the_level() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let object = ObjectId(level_state.spawn((Object::new(IVec2::ZERO), Controllable)));
+
+        level_state.next_batch(true);
+        destroy(&mut level_state, object);
+        assert_eq!(testing::sent::<LevelLost>(&level_state), 1);
+    }
 }
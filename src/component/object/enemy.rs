@@ -0,0 +1,243 @@
+use crate::{
+    action::{enemy_turn::EnemyTurn, ActionResult},
+    component::object::{self, facing, Controllable, Object},
+    direction::Direction,
+    level_state::{
+        positioning::movement::{object_cells, wall_blocks},
+        LevelState, ObjectId,
+    },
+};
+use bevy::{ecs::entity::Entity, prelude::Component};
+
+/// Object that moves on its own at the end of every turn, see [`EnemyTurn`].
+/// Enemies don't push, and catch [`Controllable`] objects next to them, see [`catch`].
+#[derive(Component, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Enemy {
+    /// Walks the steps in order, over and over. Waits while the next step is blocked.
+    Patrol(Vec<Direction>),
+    /// Walks the way it is [`Facing`](super::facing::Facing), turning around when blocked.
+    Bounce,
+    /// Steps toward the active controller along whichever axis it is further away on.
+    Follow,
+}
+
+/// Index of the next step of [`Enemy::Patrol`]. Changed only through
+/// [`SetPatrolStep`](crate::level_state::state_change::set_patrol_step::SetPatrolStep).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PatrolStep(pub(crate) usize);
+
+/// Moves every enemy, in the order of their ids.
+pub fn end_of_turn(level_state: &mut LevelState) -> ActionResult {
+    ActionResult {
+        further_actions: enemies(level_state)
+            .into_iter()
+            .map(|enemy| EnemyTurn(enemy).into())
+            .collect(),
+    }
+}
+
+/// Destroys [`Controllable`] objects anywhere in the stack on a cell next to an enemy, unless a
+/// wall that the enemy couldn't move through is in between, see [`wall_blocks`].
+/// Enemies with a [`Facing`](facing::Facing) only catch what is [`in_front`](facing::in_front)
+/// of them. Runs after every effect that moves objects, so it sees where everyone ended up.
+pub fn catch(level_state: &mut LevelState) -> ActionResult {
+    for enemy in enemies(level_state) {
+        if level_state.is_destroyed(enemy.0) {
+            continue;
+        }
+
+        let seen = if facing::facing(level_state, enemy).is_some() {
+            facing::in_front(level_state, enemy)
+        } else {
+            next_to(level_state, enemy)
+        };
+        let caught = seen
+            .into_iter()
+            .filter(|other| {
+                level_state
+                    .world()
+                    .entity(other.0)
+                    .contains::<Controllable>()
+            })
+            .collect::<Vec<_>>();
+
+        for object in caught {
+            object::destroy(level_state, object);
+        }
+    }
+
+    ActionResult::default()
+}
+
+/// Objects in the stacks on the cells around the enemy, each once.
+fn next_to(level_state: &LevelState, enemy: ObjectId) -> Vec<ObjectId> {
+    let mut objects = Vec::new();
+    for cell in object_cells(level_state, enemy) {
+        for direction in Direction::ALL {
+            if let Some(wall) = level_state.spatial_index().get_wall(cell, direction) {
+                if wall_blocks(level_state, wall, enemy, direction) {
+                    continue;
+                }
+            }
+
+            for other in level_state.spatial_index().get_stack(direction + cell) {
+                if other != enemy && !objects.contains(&other) {
+                    objects.push(other);
+                }
+            }
+        }
+    }
+    objects
+}
+
+fn enemies(level_state: &LevelState) -> Vec<ObjectId> {
+    let world = level_state.world();
+    let mut enemies = world
+        .try_query::<(Entity, &Object, &Enemy)>()
+        .expect("`Object` and `Enemy` should be registered")
+        .iter(world)
+        .map(|(entity, ..)| ObjectId(entity))
+        .collect::<Vec<_>>();
+    enemies.sort_unstable();
+    enemies
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        action,
+        component::{
+            color_barrier::ColorBarrier,
+            object::Carrier,
+            wall::{Wall, WallAlignment},
+            Group,
+        },
+        level_state::{
+            positioning::{movement::object_pos, Floor},
+            testing,
+        },
+    };
+    use bevy::math::IVec2;
+
+    #[test]
+    fn facing_enemy_catches_only_in_front() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        level_state.spawn((
+            Object::new(IVec2::ZERO),
+            Enemy::Patrol(Vec::new()),
+            facing::Facing(Direction::Right),
+        ));
+        let behind = ObjectId(level_state.spawn((Object::new(-IVec2::X), Controllable)));
+        let ahead = ObjectId(level_state.spawn((Object::new(IVec2::X), Controllable)));
+
+        level_state.next_batch(true);
+        catch(&mut level_state);
+        assert!(!level_state.is_destroyed(behind.0));
+        assert!(level_state.is_destroyed(ahead.0));
+
+        level_state.undo_batch();
+        assert!(!level_state.is_destroyed(ahead.0));
+    }
+
+    #[test]
+    fn patrol_step_is_undone_with_the_move() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        for x in 0..2 {
+            level_state.spawn(Floor::new(IVec2::new(x, 0)));
+        }
+        let enemy = ObjectId(level_state.spawn((
+            Object::new(IVec2::ZERO),
+            Enemy::Patrol(vec![Direction::Right, Direction::Left]),
+        )));
+        let step = |level_state: &LevelState| {
+            level_state
+                .world()
+                .get::<PatrolStep>(enemy.0)
+                .copied()
+                .unwrap_or_default()
+        };
+
+        level_state.next_batch(true);
+        for action in end_of_turn(&mut level_state).further_actions {
+            action::resolve(&mut level_state, action);
+        }
+        assert_eq!(object_pos(&level_state, enemy), IVec2::new(1, 0));
+        assert_eq!(step(&level_state), PatrolStep(1));
+
+        level_state.undo_batch();
+        assert_eq!(object_pos(&level_state, enemy), IVec2::ZERO);
+        assert_eq!(step(&level_state), PatrolStep(0));
+    }
+
+    #[test]
+    fn enemy_catches_controllable_on_a_carrier() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        level_state.spawn((Object::new(IVec2::ZERO), Enemy::Patrol(Vec::new())));
+        let carrier = ObjectId(level_state.spawn((Object::new(IVec2::new(1, 0)), Carrier)));
+        let player = ObjectId(level_state.spawn((
+            Object {
+                pos: IVec2::new(1, 0),
+                layer: 1,
+            },
+            Controllable,
+        )));
+
+        level_state.next_batch(true);
+        catch(&mut level_state);
+        assert!(level_state.is_destroyed(player.0));
+        assert!(!level_state.is_destroyed(carrier.0));
+    }
+
+    #[test]
+    fn color_barrier_shields_from_enemy_of_other_group() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        level_state.spawn((
+            Object::new(IVec2::ZERO),
+            Enemy::Patrol(Vec::new()),
+            Group::Blue,
+        ));
+        level_state.spawn((
+            Wall::new(IVec2::ZERO, WallAlignment::Right),
+            ColorBarrier,
+            Group::Red,
+        ));
+        let player = ObjectId(level_state.spawn((Object::new(IVec2::new(1, 0)), Controllable)));
+
+        level_state.next_batch(true);
+        catch(&mut level_state);
+        assert!(!level_state.is_destroyed(player.0));
+    }
+
+    #[test]
+    fn bouncing_enemy_turns_around_at_walls_until_undone() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let enemy = ObjectId(level_state.spawn((
+            Object::new(IVec2::ZERO),
+            Enemy::Bounce,
+            facing::Facing(Direction::Right),
+        )));
+        level_state.spawn(Wall::new(IVec2::X, WallAlignment::Right));
+        let turn = |level_state: &mut LevelState| {
+            level_state.next_batch(true);
+            for action in end_of_turn(level_state).further_actions {
+                action::resolve(level_state, action);
+            }
+        };
+
+        turn(&mut level_state);
+        assert_eq!(object_pos(&level_state, enemy), IVec2::X);
+        turn(&mut level_state);
+        assert_eq!(object_pos(&level_state, enemy), IVec2::ZERO);
+        assert_eq!(facing::facing(&level_state, enemy), Some(Direction::Left));
+
+        level_state.undo_batch();
+        assert_eq!(object_pos(&level_state, enemy), IVec2::X);
+        assert_eq!(facing::facing(&level_state, enemy), Some(Direction::Right));
+    }
+}
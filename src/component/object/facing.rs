@@ -0,0 +1,82 @@
+use crate::{
+    direction::Direction,
+    level_state::{
+        positioning::movement::{object_pos, wall_blocks},
+        LevelState, ObjectId,
+    },
+};
+use bevy::prelude::Component;
+
+/// Direction the object looks in. Changed only through
+/// [`SetFacing`](crate::level_state::state_change::set_facing::SetFacing).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Facing(pub Direction);
+
+#[inline]
+pub fn facing(level_state: &LevelState, object: ObjectId) -> Option<Direction> {
+    level_state
+        .world()
+        .get::<Facing>(object.0)
+        .map(|facing| facing.0)
+}
+
+/// Objects stacked on the cell in front of the object's position, from the ground up. Empty if
+/// the object has no [`Facing`], or a wall it couldn't move through is in between, see
+/// [`wall_blocks`].
+pub fn in_front(level_state: &LevelState, object: ObjectId) -> Vec<ObjectId> {
+    let Some(direction) = facing(level_state, object) else {
+        return Vec::new();
+    };
+    let pos = object_pos(level_state, object);
+    let spatial_index = level_state.spatial_index();
+
+    if let Some(wall) = spatial_index.get_wall(pos, direction) {
+        if wall_blocks(level_state, wall, object, direction) {
+            return Vec::new();
+        }
+    }
+    spatial_index
+        .get_stack(direction + pos)
+        .into_iter()
+        .filter(|&other| other != object)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        component::{
+            object::Carrier,
+            wall::{Opened, Wall, WallAlignment},
+        },
+        level_state::{positioning::Object, testing},
+    };
+    use bevy::math::IVec2;
+
+    #[test]
+    fn in_front_sees_the_stack_unless_a_wall_is_in_between() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let object =
+            ObjectId(level_state.spawn((Object::new(IVec2::ZERO), Facing(Direction::Right))));
+        let carrier = ObjectId(level_state.spawn((Object::new(IVec2::X), Carrier)));
+        let rider = ObjectId(level_state.spawn(Object {
+            pos: IVec2::X,
+            layer: 1,
+        }));
+        // Behind the object
+        level_state.spawn(Object::new(-IVec2::X));
+
+        assert_eq!(in_front(&level_state, object), [carrier, rider]);
+
+        let wall = level_state.spawn(Wall::new(IVec2::ZERO, WallAlignment::Right));
+        assert!(in_front(&level_state, object).is_empty());
+
+        level_state
+            .world_mut()
+            .entity_mut(wall)
+            .insert(Opened(true));
+        assert_eq!(in_front(&level_state, object), [carrier, rider]);
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{
+    direction::Direction,
+    level_state::{
+        positioning::movement::{object_cells, object_layer},
+        state_change::set_glued::SetGlued,
+        LevelState, ObjectId,
+    },
+};
+use bevy::prelude::Component;
+
+/// Objects glued to this one. Glued objects move as a single rigid body.
+/// Changed only through [`SetGlued`], which keeps both sides in sync.
+#[derive(Component, Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Glued(pub(crate) Vec<ObjectId>);
+
+impl Glued {
+    #[inline]
+    pub fn objects(&self) -> &[ObjectId] {
+        &self.0
+    }
+}
+
+/// Object that glues itself to every object on the ground it touches.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Sticky;
+
+/// The object and every object glued to it, directly or through other objects.
+/// Destroyed objects break the glue.
+pub fn group(level_state: &LevelState, object: ObjectId) -> Vec<ObjectId> {
+    let mut group = vec![object];
+    let mut i = 0;
+    while i < group.len() {
+        if let Some(glued) = level_state.world().get::<Glued>(group[i].0) {
+            for &other in &glued.0 {
+                if !group.contains(&other) && !level_state.is_destroyed(other.0) {
+                    group.push(other);
+                }
+            }
+        }
+        i += 1;
+    }
+    group
+}
+
+/// Glues the object to the objects on the ground next to it, if either of them is [`Sticky`].
+pub fn stick(level_state: &mut LevelState, object: ObjectId) {
+    if object_layer(level_state, object) > 0 {
+        return;
+    }
+
+    let world = level_state.world();
+    let sticky = world.entity(object.0).contains::<Sticky>();
+    let glued = world
+        .get::<Glued>(object.0)
+        .map(|glued| glued.0.clone())
+        .unwrap_or_default();
+
+    let mut neighbors = Vec::new();
+    for cell in object_cells(level_state, object) {
+        for direction in Direction::ALL {
+            let Some(neighbor) = level_state.spatial_index().get_object(direction + cell) else {
+                continue;
+            };
+            if neighbor != object
+                && !glued.contains(&neighbor)
+                && !neighbors.contains(&neighbor)
+                && (sticky || world.entity(neighbor.0).contains::<Sticky>())
+            {
+                neighbors.push(neighbor);
+            }
+        }
+    }
+
+    for neighbor in neighbors {
+        level_state.state_change(
+            SetGlued {
+                objects: (object, neighbor),
+                glued: true,
+            }
+            .into(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        action::willing_move::WillingMove,
+        game_loop::perform_turn,
+        level_state::{
+            positioning::{movement::object_pos, Object},
+            testing,
+        },
+        target::Target,
+    };
+    use bevy::math::IVec2;
+
+    fn step_right(level_state: &mut LevelState, object: ObjectId) {
+        let action = WillingMove {
+            target: Target::Glued(object),
+            direction: Direction::Right,
+        };
+        perform_turn(level_state, action.into());
+    }
+
+    #[test]
+    fn sticky_object_picks_up_what_it_touches() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let sticky = ObjectId(level_state.spawn((Object::new(IVec2::ZERO), Sticky)));
+        let object = ObjectId(level_state.spawn(Object::new(IVec2::new(2, 0))));
+
+        step_right(&mut level_state, sticky);
+        assert_eq!(group(&level_state, sticky), [sticky, object]);
+
+        step_right(&mut level_state, sticky);
+        assert_eq!(object_pos(&level_state, sticky), IVec2::new(2, 0));
+        assert_eq!(object_pos(&level_state, object), IVec2::new(3, 0));
+
+        level_state.undo_batch();
+        assert_eq!(object_pos(&level_state, object), IVec2::new(2, 0));
+        level_state.undo_batch();
+        assert_eq!(group(&level_state, sticky), [sticky]);
+        assert_eq!(group(&level_state, object), [object]);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{
+    action::ActionResult,
+    component::{
+        collectible::Collectible,
+        floor::{
+            goal::{Exit, Goal},
+            Floor,
+        },
+        object::{Colors, Controllable},
+        Group,
+    },
+    event::LevelCompleted,
+    level_state::{state_change::set_completed::SetCompleted, LevelState},
+};
+use bevy::{ecs::query::With, prelude::Component};
+
+pub struct RegisterObjectiveComponentsPlugin;
+
+impl bevy::app::Plugin for RegisterObjectiveComponentsPlugin {
+    fn build(&self, app: &mut bevy::app::App) {
+        let world = app.world_mut();
+        world.register_component::<Objective>();
+    }
+}
+
+/// Condition for winning the level. The level is completed when every objective in it is met.
+/// Destroyed items don't count.
+#[derive(Component, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Objective {
+    /// Every [`Goal`] floor has an object of a matching [`Group`] on it, see [`Colors::of`].
+    GoalsFilled,
+    /// Every [`Collectible`] is collected.
+    AllCollected,
+    /// A [`Controllable`] object stands on an [`Exit`] floor.
+    ExitReached,
+    All(Vec<Objective>),
+    Any(Vec<Objective>),
+}
+
+impl Objective {
+    pub fn is_met(&self, level_state: &LevelState) -> bool {
+        let world = level_state.world();
+        // `Disabled` entities are filtered out of queries by default
+        match self {
+            Objective::GoalsFilled => world
+                .try_query_filtered::<(&Floor, Option<&Group>), With<Goal>>()
+                .expect("`Floor` and `Goal` should be registered")
+                .iter(world)
+                .all(|(floor, group)| {
+                    let Some(object) = level_state.spatial_index().get_object(floor.pos()) else {
+                        return false;
+                    };
+                    group.is_none_or(|&group| Colors::of(level_state, object).contains(group))
+                }),
+            Objective::AllCollected => world
+                .try_query::<&Collectible>()
+                .expect("`Collectible` should be registered")
+                .iter(world)
+                .next()
+                .is_none(),
+            Objective::ExitReached => world
+                .try_query_filtered::<&Floor, With<Exit>>()
+                .expect("`Floor` and `Exit` should be registered")
+                .iter(world)
+                .any(|floor| {
+                    level_state
+                        .spatial_index()
+                        .get_object(floor.pos())
+                        .is_some_and(|object| world.entity(object.0).contains::<Controllable>())
+                }),
+            Objective::All(objectives) => objectives.iter().all(|o| o.is_met(level_state)),
+            Objective::Any(objectives) => objectives.iter().any(|o| o.is_met(level_state)),
+        }
+    }
+}
+
+/// Completes the level the first time every objective is met.
+pub fn end_of_turn(level_state: &mut LevelState) -> ActionResult {
+    if level_state.is_completed() {
+        return ActionResult::default();
+    }
+
+    let world = level_state.world();
+    let objectives = world
+        .try_query::<&Objective>()
+        .expect("`Objective` should be registered")
+        .iter(world)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    if !objectives.is_empty() && objectives.iter().all(|o| o.is_met(level_state)) {
+        level_state.state_change(SetCompleted(true).into());
+        level_state.interrupt();
+        let event = LevelCompleted {
+            moves: level_state.moves(),
+            undos: level_state.undos(),
+        };
+        level_state.send_event(event);
+    }
+
+    ActionResult::default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level_state::{positioning::Object, testing};
+    use bevy::math::IVec2;
+
+    #[test]
+    fn level_is_completed_once_until_undone() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        level_state.spawn(Objective::ExitReached);
+        level_state.spawn((Floor::new(IVec2::ZERO), Exit));
+        level_state.spawn((Object::new(IVec2::ZERO), Controllable));
+
+        level_state.next_batch(true);
+        end_of_turn(&mut level_state);
+        assert!(level_state.is_completed());
+        level_state.next_batch(true);
+        end_of_turn(&mut level_state);
+        assert_eq!(testing::sent::<LevelCompleted>(&level_state), 1);
+
+        level_state.undo_batch();
+        assert!(level_state.is_completed());
+        level_state.undo_batch();
+        assert!(!level_state.is_completed());
+
+        level_state.next_batch(true);
+        end_of_turn(&mut level_state);
+        assert_eq!(testing::sent::<LevelCompleted>(&level_state), 2);
+    }
+}
@@ -1,15 +1,26 @@
 use bevy::prelude::Component;
 
+pub mod breakable;
+pub mod door;
+pub mod lock;
+pub mod one_way;
+
 pub struct RegisterWallComponentsPlugin;
 
 impl bevy::app::Plugin for RegisterWallComponentsPlugin {
     fn build(&self, app: &mut bevy::app::App) {
         let world = app.world_mut();
         world.register_component::<Wall>();
+        world.register_component::<OnActivated>();
+        world.register_component::<Opened>();
+        world.register_component::<breakable::Breakable>();
+        world.register_component::<door::Door>();
+        world.register_component::<lock::Locked>();
+        world.register_component::<one_way::OneWay>();
     }
 }
 
-pub use crate::level_state::positioning::Wall;
+pub use crate::level_state::positioning::{Wall, WallAlignment};
 use crate::{action::ActionResult, level_state::{LevelState, WallId}};
 
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -22,6 +33,8 @@ impl Default for Opened {
     }
 }
 
+/// Called by [`Activate`](crate::action::activate::Activate).
+#[derive(Component, Clone, Copy)]
 pub struct OnActivated {
-    callback: fn(WallId, &mut LevelState) -> ActionResult,
+    pub callback: fn(WallId, &mut LevelState) -> ActionResult,
 }
@@ -0,0 +1,143 @@
+use crate::level_state::{
+    state_change::{crack::Crack, destroy::Destroy},
+    ItemId, LevelState, WallId,
+};
+use bevy::prelude::Component;
+
+/// Wall that loses a hit point every time a move is blocked by it, whatever made the move, and is
+/// destroyed at zero. Explosions destroy it right away.
+/// Changed only through [`Crack`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Breakable {
+    pub(crate) hit_points: u32,
+}
+
+impl Breakable {
+    #[inline]
+    pub fn new(hit_points: u32) -> Self {
+        Self { hit_points }
+    }
+
+    #[inline]
+    pub fn hit_points(&self) -> u32 {
+        self.hit_points
+    }
+}
+
+/// Called by [`bumped`](crate::action::bumped) when a move is blocked by the wall.
+/// Does nothing to unbreakable walls.
+pub fn hit(level_state: &mut LevelState, wall: WallId) {
+    let Some(&breakable) = level_state.world().get::<Breakable>(wall.0) else {
+        return;
+    };
+    if breakable.hit_points == 0 {
+        return;
+    }
+
+    level_state.state_change(Crack { wall, hits: -1 }.into());
+    level_state.interrupt();
+
+    if breakable.hit_points == 1 {
+        level_state.state_change(Destroy(ItemId::Wall(wall)).into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        action::{self, slide::Slide},
+        component::{
+            floor::ice::Ice,
+            wall::{Wall, WallAlignment},
+        },
+        direction::Direction,
+        level_state::{
+            positioning::{movement::object_pos, Floor, Object},
+            testing, ObjectId,
+        },
+    };
+    use bevy::math::IVec2;
+
+    fn hit_points(level_state: &LevelState, wall: WallId) -> u32 {
+        level_state
+            .world()
+            .get::<Breakable>(wall.0)
+            .unwrap()
+            .hit_points()
+    }
+
+    #[test]
+    fn undo_repairs_the_wall() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let wall = WallId(
+            level_state.spawn((Wall::new(IVec2::ZERO, WallAlignment::Up), Breakable::new(2))),
+        );
+
+        level_state.next_batch(true);
+        hit(&mut level_state, wall);
+        level_state.next_batch(true);
+        hit(&mut level_state, wall);
+        assert_eq!(hit_points(&level_state, wall), 0);
+        assert!(level_state.is_destroyed(wall.0));
+
+        level_state.undo_batch();
+        assert_eq!(hit_points(&level_state, wall), 1);
+        assert!(!level_state.is_destroyed(wall.0));
+        assert_eq!(
+            level_state
+                .spatial_index()
+                .get_wall(IVec2::ZERO, Direction::Up),
+            Some(wall)
+        );
+
+        level_state.undo_batch();
+        assert_eq!(hit_points(&level_state, wall), 2);
+    }
+
+    #[test]
+    fn wall_without_hit_points_does_not_break() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let wall = WallId(
+            level_state.spawn((Wall::new(IVec2::ZERO, WallAlignment::Up), Breakable::new(0))),
+        );
+
+        level_state.next_batch(true);
+        hit(&mut level_state, wall);
+        assert_eq!(hit_points(&level_state, wall), 0);
+        assert!(!level_state.is_destroyed(wall.0));
+    }
+
+    #[test]
+    fn sliding_into_the_wall_cracks_it() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        for x in 0..2 {
+            level_state.spawn((Floor::new(IVec2::new(x, 0)), Ice));
+        }
+        let object = ObjectId(level_state.spawn(Object::new(IVec2::ZERO)));
+        let wall = WallId(level_state.spawn((
+            Wall::new(IVec2::new(1, 0), WallAlignment::Right),
+            Breakable::new(2),
+        )));
+
+        level_state.next_batch(true);
+        action::resolve(
+            &mut level_state,
+            Slide {
+                object,
+                direction: Direction::Right,
+            }
+            .into(),
+        );
+
+        assert_eq!(object_pos(&level_state, object), IVec2::new(1, 0));
+        assert_eq!(hit_points(&level_state, wall), 1);
+
+        level_state.undo_batch();
+        assert_eq!(object_pos(&level_state, object), IVec2::ZERO);
+        assert_eq!(hit_points(&level_state, wall), 2);
+    }
+}
@@ -0,0 +1,184 @@
+use crate::{
+    action::ActionResult,
+    component::{
+        floor::{
+            pressure_plate::{self, PressurePlate},
+            Floor,
+        },
+        Group,
+    },
+    direction::Direction,
+    level_state::{state_change::set_opened::SetOpened, FloorId, LevelState, WallId},
+};
+use bevy::{ecs::entity::Entity, prelude::Component};
+
+use super::{Opened, Wall, WallAlignment};
+
+/// Wall that is [`Opened`] by the [`PressurePlate`]s of the same [`Group`].
+/// Doors of a group without plates are left as they are.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Door {
+    /// Opened while every plate is pressed.
+    #[default]
+    All,
+    /// Opened while at least one plate is pressed.
+    Any,
+}
+
+/// Opens and closes doors according to their plates. A door doesn't close on an object standing
+/// in the doorway, see [`is_doorway_occupied`].
+pub fn end_of_turn(level_state: &mut LevelState) -> ActionResult {
+    let world = level_state.world();
+
+    let plates = world
+        .try_query::<(Entity, &Group, &Floor, &PressurePlate)>()
+        .expect("`Floor` and `PressurePlate` should be registered")
+        .iter(world)
+        .map(|(entity, &group, ..)| (group, FloorId(entity)))
+        .collect::<Vec<_>>();
+
+    let mut doors = world
+        .try_query::<(Entity, &Group, &Door, Option<&Opened>, &Wall)>()
+        .expect("`Wall` and `Door` should be registered")
+        .iter(world)
+        .map(|(entity, &group, &door, opened, _)| {
+            (
+                WallId(entity),
+                group,
+                door,
+                opened.copied().unwrap_or_default(),
+            )
+        })
+        .collect::<Vec<_>>();
+    doors.sort_unstable_by_key(|&(wall, ..)| wall);
+
+    for (wall, group, door, opened) in doors {
+        let mut pressed = plates
+            .iter()
+            .filter(|&&(plate_group, _)| plate_group == group)
+            .map(|&(_, plate)| pressure_plate::is_pressed(level_state, plate))
+            .peekable();
+        if pressed.peek().is_none() {
+            continue;
+        }
+
+        let should_open = match door {
+            Door::All => pressed.all(|pressed| pressed),
+            Door::Any => pressed.any(|pressed| pressed),
+        };
+
+        if !should_open && opened.0 && is_doorway_occupied(level_state, wall) {
+            continue;
+        }
+
+        if should_open != opened.0 {
+            level_state.state_change(
+                SetOpened {
+                    wall,
+                    opened: should_open,
+                }
+                .into(),
+            );
+            level_state.interrupt();
+        }
+    }
+
+    ActionResult::default()
+}
+
+/// Whether an object stands in the doorway, occupying the cells on both sides of the wall.
+/// Walls lie between cells, so only objects with a [`Shape`](crate::component::object::Shape)
+/// can do that.
+pub fn is_doorway_occupied(level_state: &LevelState, wall: WallId) -> bool {
+    let wall = *level_state
+        .world()
+        .get::<Wall>(wall.0)
+        .expect("`WallId` should point to an entity with `Wall` component");
+    let across = match wall.alignment() {
+        WallAlignment::Up => Direction::Up,
+        WallAlignment::Right => Direction::Right,
+    };
+
+    let spatial_index = level_state.spatial_index();
+    let other_side = spatial_index.get_stack(across + wall.pos());
+    spatial_index
+        .get_stack(wall.pos())
+        .into_iter()
+        .any(|object| other_side.contains(&object))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        component::object::Shape,
+        level_state::{
+            positioning::{movement::move_target, Object},
+            testing, ObjectId,
+        },
+        target::Target,
+    };
+    use bevy::math::IVec2;
+
+    fn opened(level_state: &LevelState, wall: WallId) -> bool {
+        level_state
+            .world()
+            .get::<Opened>(wall.0)
+            .is_some_and(|opened| opened.0)
+    }
+
+    fn door(level_state: &mut LevelState, door: Door) -> WallId {
+        WallId(level_state.spawn((Wall::new(IVec2::ZERO, WallAlignment::Up), door, Group::Red)))
+    }
+
+    fn plate(level_state: &mut LevelState, x: i32) {
+        level_state.spawn((Floor::new(IVec2::new(x, 0)), PressurePlate, Group::Red));
+    }
+
+    #[test]
+    fn door_follows_its_plates() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let all = door(&mut level_state, Door::All);
+        let any = door(&mut level_state, Door::Any);
+        plate(&mut level_state, 5);
+        plate(&mut level_state, 6);
+        level_state.spawn(Object::new(IVec2::new(5, 0)));
+
+        level_state.next_batch(true);
+        end_of_turn(&mut level_state);
+        assert!(!opened(&level_state, all));
+        assert!(opened(&level_state, any));
+
+        level_state.undo_batch();
+        assert!(!opened(&level_state, any));
+    }
+
+    #[test]
+    fn door_does_not_close_on_object_in_doorway() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        plate(&mut level_state, 5);
+        let wall = door(&mut level_state, Door::All);
+        level_state
+            .world_mut()
+            .entity_mut(wall.0)
+            .insert(Opened(true));
+        let object = ObjectId(
+            level_state.spawn((Object::new(IVec2::ZERO), Shape(vec![IVec2::ZERO, IVec2::Y]))),
+        );
+
+        level_state.next_batch(true);
+        end_of_turn(&mut level_state);
+        assert!(opened(&level_state, wall));
+
+        // Out of the doorway
+        level_state.next_batch(true);
+        move_target(&mut level_state, &Target::Object(object), Direction::Right);
+        end_of_turn(&mut level_state);
+        assert!(!opened(&level_state, wall));
+
+        level_state.undo_batch();
+        assert!(opened(&level_state, wall));
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{
+    component::{collectible::key::Inventory, Group},
+    level_state::{
+        state_change::{change_keys::ChangeKeys, destroy::Destroy, set_opened::SetOpened},
+        ItemId, LevelState, ObjectId, WallId,
+    },
+};
+use bevy::prelude::Component;
+
+/// Wall that an object carrying a [`Key`](crate::component::collectible::key::Key) of the same
+/// [`Group`] unlocks by bumping into it. Unlocking spends the key.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Locked {
+    /// The wall becomes [`Opened`](super::Opened).
+    #[default]
+    Opens,
+    /// The wall is destroyed.
+    Consumed,
+}
+
+/// Returns `true` if the object had a key and the wall was unlocked.
+pub fn try_unlock(level_state: &mut LevelState, object: ObjectId, wall: WallId) -> bool {
+    let world = level_state.world();
+    let (Some(&locked), Some(&group)) = (world.get::<Locked>(wall.0), world.get::<Group>(wall.0))
+    else {
+        return false;
+    };
+    // `ChangeKeys` would take nothing without a key, but the lock must stay locked too
+    let has_key = world
+        .get::<Inventory>(object.0)
+        .is_some_and(|inventory| inventory.keys(group) > 0);
+    if !has_key {
+        return false;
+    }
+
+    level_state.state_change(
+        ChangeKeys {
+            object,
+            group,
+            amount: -1,
+        }
+        .into(),
+    );
+    match locked {
+        Locked::Opens => level_state.state_change(SetOpened { wall, opened: true }.into()),
+        Locked::Consumed => level_state.state_change(Destroy(ItemId::Wall(wall)).into()),
+    }
+
+    level_state.interrupt();
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        component::wall::{Opened, Wall, WallAlignment},
+        level_state::{positioning::Object, testing},
+    };
+    use bevy::math::IVec2;
+
+    fn keys(level_state: &LevelState, object: ObjectId) -> u32 {
+        level_state
+            .world()
+            .get::<Inventory>(object.0)
+            .unwrap()
+            .keys(Group::Red)
+    }
+
+    #[test]
+    fn unlocking_spends_a_key_until_undone() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let object = ObjectId(level_state.spawn((Object::new(IVec2::ZERO), Inventory::default())));
+        let wall = WallId(level_state.spawn((
+            Wall::new(IVec2::ZERO, WallAlignment::Right),
+            Locked::Opens,
+            Group::Red,
+        )));
+
+        level_state.next_batch(true);
+        level_state.state_change(
+            ChangeKeys {
+                object,
+                group: Group::Red,
+                amount: 1,
+            }
+            .into(),
+        );
+        level_state.next_batch(true);
+        assert!(try_unlock(&mut level_state, object, wall));
+        assert_eq!(keys(&level_state, object), 0);
+        assert_eq!(
+            level_state.world().get::<Opened>(wall.0),
+            Some(&Opened(true))
+        );
+
+        level_state.undo_batch();
+        assert_eq!(keys(&level_state, object), 1);
+        assert!(!level_state
+            .world()
+            .get::<Opened>(wall.0)
+            .is_some_and(|opened| opened.0));
+
+        level_state.undo_batch();
+        assert_eq!(keys(&level_state, object), 0);
+    }
+
+    #[test]
+    fn unlocking_without_a_key_takes_nothing() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let object = ObjectId(level_state.spawn((Object::new(IVec2::ZERO), Inventory::default())));
+        let wall = WallId(level_state.spawn((
+            Wall::new(IVec2::ZERO, WallAlignment::Right),
+            Locked::Consumed,
+            Group::Red,
+        )));
+
+        level_state.next_batch(true);
+        assert!(!try_unlock(&mut level_state, object, wall));
+        assert_eq!(keys(&level_state, object), 0);
+        assert!(!level_state.is_destroyed(wall.0));
+    }
+}
@@ -0,0 +1,29 @@
+use super::{Wall, WallAlignment};
+use crate::direction::Direction;
+use bevy::{math::IVec2, prelude::Component};
+
+/// Wall that objects can cross only while moving in the direction, for example
+/// `OneWay(Direction::Down)` is a ledge that can be jumped off but not climbed.
+///
+/// CORRECTNESS: The direction should cross the wall, so `Up` or `Down` for walls aligned `Up`,
+/// and `Left` or `Right` for walls aligned `Right`.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OneWay(pub Direction);
+
+impl OneWay {
+    /// Cell on the side the wall can be crossed from.
+    pub fn entry(self, wall: &Wall) -> IVec2 {
+        -self.0 + self.exit(wall)
+    }
+
+    /// Cell on the side the wall leads to.
+    pub fn exit(self, wall: &Wall) -> IVec2 {
+        debug_assert!(wall.is_crossed_by(self.0));
+        match (wall.alignment(), self.0) {
+            (WallAlignment::Up, Direction::Up) | (WallAlignment::Right, Direction::Right) => {
+                self.0 + wall.pos()
+            }
+            _ => wall.pos(),
+        }
+    }
+}
@@ -33,6 +33,33 @@ impl Neg for Direction {
     }
 }
 
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Down,
+        Direction::Up,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    pub fn rotate_clockwise(self) -> Self {
+        match self {
+            Direction::Down => Direction::Left,
+            Direction::Up => Direction::Right,
+            Direction::Left => Direction::Up,
+            Direction::Right => Direction::Down,
+        }
+    }
+
+    pub fn rotate_counterclockwise(self) -> Self {
+        match self {
+            Direction::Down => Direction::Right,
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Right => Direction::Up,
+        }
+    }
+}
+
 impl Add<IVec2> for Direction {
     type Output = IVec2;
 
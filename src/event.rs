@@ -0,0 +1,49 @@
+use crate::level_state::{ObjectId, WallId};
+use bevy::{
+    app::{App, Plugin},
+    ecs::event::Event,
+    math::IVec2,
+};
+
+/// Events that presentation code listens to. They are sent both when state changes are applied and
+/// when they are undone, so the renderer can play the same effect backwards.
+pub struct LevelEventsPlugin;
+
+impl Plugin for LevelEventsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<Teleported>()
+            .add_event::<WallCracked>()
+            .add_event::<LevelCompleted>()
+            .add_event::<LevelLost>();
+    }
+}
+
+/// Object moved between linked [`Teleporter`](crate::component::floor::teleporter::Teleporter)s.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Teleported {
+    pub object: ObjectId,
+    pub from: IVec2,
+    pub to: IVec2,
+}
+
+/// [`Breakable`](crate::component::wall::breakable::Breakable) wall was hit, or a hit was undone.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WallCracked {
+    pub wall: WallId,
+    /// Hit points the wall has left. The wall is destroyed at zero.
+    pub hit_points: u32,
+}
+
+/// Every [`Objective`](crate::component::objective::Objective) of the level is met for the first
+/// time since the level started, or since the winning turn was undone.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LevelCompleted {
+    /// Turns made that passed time, including the undone ones.
+    pub moves: u32,
+    /// Turns undone.
+    pub undos: u32,
+}
+
+/// Controllable object was lost, the level can't be completed without undoing.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LevelLost;
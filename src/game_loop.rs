@@ -1,13 +1,360 @@
-use bevy::app::{App, Plugin};
+use std::collections::VecDeque;
 
+use crate::{
+    action::{
+        self, push::push_target, willing_move::WillingMove, Action, ActionEnum, ActionResult,
+    },
+    component::{
+        bomb,
+        floor::{conveyor, water},
+        laser, logic,
+        object::enemy,
+        objective,
+        wall::door,
+    },
+    direction::Direction,
+    level_state::{
+        positioning::{
+            movement::{can_move, object_pos, CanMove},
+            pathfinding,
+        },
+        LevelState, ObjectId,
+    },
+    target::Target,
+};
+use bevy::{
+    app::{App, Plugin},
+    math::IVec2,
+};
 
 pub struct GameLoopPlugin;
 
 impl Plugin for GameLoopPlugin {
-    fn build(&self, _app: &mut App) {
+    fn build(&self, _app: &mut App) {}
+}
+
+pub type EndOfTurnEffect = fn(&mut LevelState) -> ActionResult;
+
+/// Environmental effects in the order they run at the end of every turn.
+/// Each effect is fully resolved before the next one starts.
+pub const END_OF_TURN_EFFECTS: &[EndOfTurnEffect] = &[
+    conveyor::end_of_turn,
+    water::end_of_turn,
+    enemy::end_of_turn,
+    bomb::end_of_turn,
+    laser::end_of_turn,
+    logic::end_of_turn,
+    door::end_of_turn,
+    enemy::catch,
+    // Should stay last, so it sees the board after every other effect
+    objective::end_of_turn,
+];
+
+/// Upper bound on steps of a single [`run`] or [`move_to`], so runs along conveyors can't loop
+/// forever.
+const MAX_STEPS: usize = 256;
+
+/// Resolves the player's action and the end of turn effects as a single undoable turn.
+/// Actions that pass no time and change nothing, like cycling to the only controller, aren't
+/// recorded, so the next undo still undoes the last turn the player made.
+pub fn perform_turn(level_state: &mut LevelState, action: ActionEnum) {
+    let passes_time = action.passes_time(level_state);
+
+    level_state.next_batch(passes_time);
+    action::resolve(level_state, action);
+
+    if passes_time {
+        end_of_turn(level_state);
+    } else {
+        level_state.discard_empty_batch();
     }
 }
 
-fn game_loop() {
+/// Moves the object in the direction until it can't move without pushing, or until something
+/// worth the player's attention happens, like collecting an item or a door changing state.
+/// See [`LevelState::interrupt`].
+///
+/// Every step is a separate turn, unless `single_turn` is set. Then the end of turn effects run
+/// once, after the last step.
+pub fn run(
+    level_state: &mut LevelState,
+    object: ObjectId,
+    direction: Direction,
+    single_turn: bool,
+) {
+    perform_steps(level_state, object, single_turn, |level_state| {
+        can_step(level_state, object, direction).then_some(direction)
+    });
+}
+
+/// Walks the object's position to the cell along a path found by [`pathfinding::find_path`],
+/// without pushing anything. Stops early if the object gets blocked, ends up off the path or
+/// gets interrupted like in [`run`].
+///
+/// Every step is a separate turn, unless `single_turn` is set, like in [`run`].
+pub fn move_to(level_state: &mut LevelState, object: ObjectId, to: IVec2, single_turn: bool) {
+    let Some(path) = pathfinding::find_path(level_state, object, to) else {
+        return;
+    };
+    let mut path = path.into_iter();
+    let mut expected = object_pos(level_state, object);
+
+    perform_steps(level_state, object, single_turn, |level_state| {
+        // Ice, conveyors or teleporters carried the object somewhere else
+        if object_pos(level_state, object) != expected {
+            return None;
+        }
+        let direction = path.next()?;
+        if !can_step(level_state, object, direction) {
+            return None;
+        }
+        expected = direction + expected;
+        Some(direction)
+    });
+}
+
+/// Path to a cell, walked one turn per step, so every step can be shown and undone on its own.
+/// Used for click-to-move. Unlike [`move_to`], the path can push objects if asked to.
+pub struct PlannedPath {
+    object: ObjectId,
+    allow_pushing: bool,
+    steps: VecDeque<WillingMove>,
+}
+
+impl PlannedPath {
+    /// `None` if the cell can't be reached.
+    pub fn new(
+        level_state: &LevelState,
+        object: ObjectId,
+        to: IVec2,
+        allow_pushing: bool,
+    ) -> Option<Self> {
+        let path = if allow_pushing {
+            pathfinding::find_pushing_path(level_state, object, to)
+        } else {
+            pathfinding::find_path(level_state, object, to)
+        };
+        let steps = path?
+            .into_iter()
+            .map(|direction| WillingMove {
+                target: Target::Glued(object),
+                direction,
+            })
+            .collect();
+
+        Some(Self {
+            object,
+            allow_pushing,
+            steps,
+        })
+    }
 
-}
\ No newline at end of file
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Performs the next step as its own turn. The rest of the path is dropped if the step
+    /// can't be made as planned, the object ends up off the path, or the step is interrupted
+    /// like in [`run`]. Returns `false` once the path is finished.
+    pub fn perform_step(&mut self, level_state: &mut LevelState) -> bool {
+        let Some(step) = self.steps.pop_front() else {
+            return false;
+        };
+
+        let can_move = if self.allow_pushing {
+            push_target(level_state, step.target.clone(), step.direction).1
+        } else {
+            can_move(level_state, &step.target, step.direction)
+        };
+        if !matches!(can_move, CanMove::Can) {
+            self.steps.clear();
+            return false;
+        }
+
+        let expected = step.direction + object_pos(level_state, self.object);
+        level_state.take_interrupt();
+        perform_turn(level_state, step.into());
+
+        if level_state.take_interrupt()
+            || level_state.is_destroyed(self.object.0)
+            || object_pos(level_state, self.object) != expected
+        {
+            self.steps.clear();
+        }
+
+        !self.is_finished()
+    }
+}
+
+/// Moves the object in the directions `next_step` returns, until it returns `None` or the level
+/// gets interrupted.
+fn perform_steps(
+    level_state: &mut LevelState,
+    object: ObjectId,
+    single_turn: bool,
+    mut next_step: impl FnMut(&LevelState) -> Option<Direction>,
+) {
+    level_state.take_interrupt();
+
+    let mut performed = 0;
+    while performed < MAX_STEPS && !level_state.is_destroyed(object.0) {
+        let Some(direction) = next_step(level_state) else {
+            break;
+        };
+        let action = WillingMove {
+            target: Target::Glued(object),
+            direction,
+        }
+        .into();
+
+        if single_turn {
+            if performed == 0 {
+                level_state.next_batch(true);
+            }
+            action::resolve(level_state, action);
+        } else {
+            perform_turn(level_state, action);
+        }
+
+        performed += 1;
+        if level_state.take_interrupt() {
+            break;
+        }
+    }
+
+    if single_turn && performed > 0 {
+        end_of_turn(level_state);
+    }
+}
+
+fn can_step(level_state: &LevelState, object: ObjectId, direction: Direction) -> bool {
+    matches!(
+        can_move(level_state, &Target::Glued(object), direction),
+        CanMove::Can
+    )
+}
+
+fn end_of_turn(level_state: &mut LevelState) {
+    for effect in END_OF_TURN_EFFECTS {
+        for action in effect(level_state).further_actions {
+            action::resolve(level_state, action);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        action::{cycle_controller::CycleController, wait::Wait},
+        component::{
+            bomb::{Bomb, Fuse},
+            object::{Controllable, NeedsWalkableFloor},
+        },
+        level_state::{
+            positioning::{Floor, Object},
+            testing,
+        },
+    };
+
+    fn turns_left(level_state: &LevelState, bomb: ObjectId) -> u32 {
+        level_state
+            .world()
+            .get::<Fuse>(bomb.0)
+            .unwrap()
+            .turns_left()
+    }
+
+    /// Object at the start of a row of four floors, and a bomb off to the side to count turns.
+    fn row(level_state: &mut LevelState) -> (ObjectId, ObjectId) {
+        for x in 0..4 {
+            level_state.spawn(Floor::new(IVec2::new(x, 0)));
+        }
+        let object =
+            ObjectId(level_state.spawn((Object::new(IVec2::ZERO), NeedsWalkableFloor::default())));
+        let bomb = ObjectId(level_state.spawn((
+            Object::new(IVec2::new(0, 5)),
+            Bomb { radius: 0 },
+            Fuse::new(10),
+        )));
+        (object, bomb)
+    }
+
+    #[test]
+    fn only_turns_that_pass_time_count_as_moves() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        level_state.spawn((Object::new(IVec2::ZERO), Controllable));
+
+        perform_turn(&mut level_state, CycleController.into());
+        assert_eq!(level_state.moves(), 0);
+        perform_turn(&mut level_state, Wait.into());
+        assert_eq!(level_state.moves(), 1);
+    }
+
+    #[test]
+    fn run_makes_a_turn_per_step() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let (object, bomb) = row(&mut level_state);
+
+        run(&mut level_state, object, Direction::Right, false);
+        assert_eq!(object_pos(&level_state, object), IVec2::new(3, 0));
+        assert_eq!(turns_left(&level_state, bomb), 7);
+        assert_eq!(level_state.moves(), 3);
+
+        level_state.undo_batch();
+        assert_eq!(object_pos(&level_state, object), IVec2::new(2, 0));
+        assert_eq!(turns_left(&level_state, bomb), 8);
+    }
+
+    #[test]
+    fn single_turn_run_passes_time_once() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let (object, bomb) = row(&mut level_state);
+
+        run(&mut level_state, object, Direction::Right, true);
+        assert_eq!(object_pos(&level_state, object), IVec2::new(3, 0));
+        assert_eq!(turns_left(&level_state, bomb), 9);
+        assert_eq!(level_state.moves(), 1);
+
+        level_state.undo_batch();
+        assert_eq!(object_pos(&level_state, object), IVec2::ZERO);
+        assert_eq!(turns_left(&level_state, bomb), 10);
+    }
+
+    #[test]
+    fn planned_path_takes_a_turn_per_step() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let (object, _) = row(&mut level_state);
+        let mut path = PlannedPath::new(&level_state, object, IVec2::new(2, 0), false).unwrap();
+
+        assert!(path.perform_step(&mut level_state));
+        assert_eq!(object_pos(&level_state, object), IVec2::X);
+        assert!(!path.perform_step(&mut level_state));
+        assert!(path.is_finished());
+        assert_eq!(object_pos(&level_state, object), IVec2::new(2, 0));
+        assert_eq!(level_state.moves(), 2);
+
+        level_state.undo_batch();
+        assert_eq!(object_pos(&level_state, object), IVec2::X);
+    }
+
+    #[test]
+    fn planned_path_stops_when_blocked() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let (object, _) = row(&mut level_state);
+        let mut path = PlannedPath::new(&level_state, object, IVec2::new(3, 0), false).unwrap();
+        level_state.spawn(Object::new(IVec2::new(2, 0)));
+
+        assert!(path.perform_step(&mut level_state));
+        assert!(!path.perform_step(&mut level_state));
+        assert!(path.is_finished());
+        assert_eq!(object_pos(&level_state, object), IVec2::X);
+        assert_eq!(level_state.moves(), 1);
+    }
+}
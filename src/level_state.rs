@@ -1,21 +1,25 @@
-use bevy::ecs::{component::Component, entity::Entity, world::World};
+use bevy::ecs::{
+    component::Component, entity::Entity, entity_disabling::Disabled, event::Event, world::World,
+};
 use positioning::spatial_index::SpatialIndex;
 use state_change::{StateChangeEnum, UndoEnum};
 
 pub mod positioning;
 pub mod state_change;
+#[cfg(test)]
+pub(crate) mod testing;
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
-pub struct CollectibleId(Entity);
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CollectibleId(pub(crate) Entity);
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
-pub struct FloorId(Entity);
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FloorId(pub(crate) Entity);
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
-pub struct ObjectId(Entity);
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ObjectId(pub(crate) Entity);
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
-pub struct WallId(Entity);
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WallId(pub(crate) Entity);
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum ItemId {
@@ -36,10 +40,22 @@ impl ItemId {
     }
 }
 
-#[derive(Component)]
+#[derive(Component, Default)]
 pub struct LevelRoot {
     spatial_index: SpatialIndex,
     undo_stack: Vec<UndoEnum>,
+    /// Object that receives player input. Changed only through
+    /// [`state_change::switch_controller::SwitchController`].
+    active_controller: Option<ObjectId>,
+    /// Turns made that passed time, including the undone ones.
+    moves: u32,
+    /// Turns undone.
+    undos: u32,
+    /// Set by [`LevelState::interrupt`], not recorded on the undo stack.
+    interrupted: bool,
+    /// Whether the level was completed. Changed only through
+    /// [`state_change::set_completed::SetCompleted`], so undoing the winning turn resets it.
+    completed: bool,
 }
 
 pub struct LevelState<'w> {
@@ -48,6 +64,63 @@ pub struct LevelState<'w> {
 }
 
 impl<'w> LevelState<'w> {
+    #[inline]
+    pub fn world(&self) -> &World {
+        self.world
+    }
+
+    #[inline]
+    pub fn spatial_index(&self) -> &SpatialIndex {
+        &self.root.spatial_index
+    }
+
+    /// Returns `true` if the item was [`Destroy`](state_change::destroy::Destroy)ed.
+    #[inline]
+    pub fn is_destroyed(&self, entity: Entity) -> bool {
+        self.world.entity(entity).contains::<Disabled>()
+    }
+
+    /// Active controller, unless it was destroyed.
+    pub fn active_controller(&self) -> Option<ObjectId> {
+        self.root
+            .active_controller
+            .filter(|object| !self.is_destroyed(object.0))
+    }
+
+    #[inline]
+    pub fn moves(&self) -> u32 {
+        self.root.moves
+    }
+
+    #[inline]
+    pub fn undos(&self) -> u32 {
+        self.root.undos
+    }
+
+    #[inline]
+    pub fn is_completed(&self) -> bool {
+        self.root.completed
+    }
+
+    /// Asks moves that repeat over several steps, like [`game_loop::run`](crate::game_loop::run),
+    /// to stop after the current step. Called when something happens that the player should see
+    /// before moving on.
+    #[inline]
+    pub fn interrupt(&mut self) {
+        self.root.interrupted = true;
+    }
+
+    /// Whether [`LevelState::interrupt`] was called since the last call, and resets it.
+    #[inline]
+    pub(crate) fn take_interrupt(&mut self) -> bool {
+        std::mem::take(&mut self.root.interrupted)
+    }
+
+    /// Sends an event for presentation code. Not recorded on the undo stack.
+    pub fn send_event<E: Event>(&mut self, event: E) {
+        self.world.send_event(event);
+    }
+
     pub fn state_change(&mut self, state_change: StateChangeEnum) {
         let undo = state_change.apply(self);
         self.root.undo_stack.push(undo);
@@ -58,4 +131,35 @@ impl<'w> LevelState<'w> {
             undo.undo(self);
         }
     }
+
+    /// Marks the start of a new turn on the undo stack. Only turns that pass time count as moves.
+    pub fn next_batch(&mut self, passes_time: bool) {
+        if passes_time {
+            self.root.moves += 1;
+        }
+        self.root.undo_stack.push(UndoEnum::NextBatch);
+    }
+
+    /// Removes the last [`UndoEnum::NextBatch`] if nothing was recorded after it, so a turn that
+    /// changed nothing doesn't take an undo.
+    pub(crate) fn discard_empty_batch(&mut self) {
+        if let Some(UndoEnum::NextBatch) = self.root.undo_stack.last() {
+            self.root.undo_stack.pop();
+        }
+    }
+
+    /// Undoes every state change up to and including the last [`UndoEnum::NextBatch`].
+    pub fn undo_batch(&mut self) {
+        if self.root.undo_stack.is_empty() {
+            return;
+        }
+        self.root.undos += 1;
+
+        while let Some(undo) = self.root.undo_stack.pop() {
+            if let UndoEnum::NextBatch = undo {
+                break;
+            }
+            undo.undo(self);
+        }
+    }
 }
@@ -1,6 +1,8 @@
-use bevy::{ecs::{component::Component, world::EntityWorldMut}, math::IVec2};
+use crate::direction::Direction;
+use bevy::{ecs::{component::Component, world::{EntityRef, EntityWorldMut}}, math::IVec2};
 
-// pub mod movement;
+pub mod movement;
+pub mod pathfinding;
 pub mod spatial_index;
 
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -17,10 +19,70 @@ pub struct Floor {
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Object {
     pub(crate) pos: IVec2,
+    /// Height in the stack on the cell, `0` is the ground.
+    /// Objects above the ground sit on a [`Carrier`](crate::component::object::Carrier).
+    pub(crate) layer: u32,
 }
 
+impl Collectible {
+    #[inline]
+    pub fn new(pos: IVec2) -> Self {
+        Self { pos }
+    }
+
+    #[inline]
+    pub fn pos(&self) -> IVec2 {
+        self.pos
+    }
+}
+
+impl Floor {
+    #[inline]
+    pub fn new(pos: IVec2) -> Self {
+        Self { pos }
+    }
+
+    #[inline]
+    pub fn pos(&self) -> IVec2 {
+        self.pos
+    }
+}
+
+impl Object {
+    #[inline]
+    pub fn new(pos: IVec2) -> Self {
+        Self { pos, layer: 0 }
+    }
+
+    #[inline]
+    pub fn pos(&self) -> IVec2 {
+        self.pos
+    }
+
+    #[inline]
+    pub fn layer(&self) -> u32 {
+        self.layer
+    }
+
+    /// Cells the object occupies when it has the shape.
+    pub fn cells(&self, shape: Option<&Shape>) -> Vec<IVec2> {
+        match shape {
+            Some(shape) => shape.0.iter().map(|&offset| self.pos + offset).collect(),
+            None => vec![self.pos],
+        }
+    }
+}
+
+/// Cells of an object relative to its position. Objects without it occupy a single cell.
+///
+/// CORRECTNESS: Should contain `IVec2::ZERO` and should not change while the object is in the
+/// `SpatialIndex`.
+#[derive(Component, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Shape(pub Vec<IVec2>);
+
+/// Edge of the cell at [`Wall::pos`] that the wall lies on.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum WallAlignment {
+pub enum WallAlignment {
     Up,
     Right,
 }
@@ -31,6 +93,32 @@ pub struct Wall {
     alignment: WallAlignment,
 }
 
+impl Wall {
+    #[inline]
+    pub fn new(pos: IVec2, alignment: WallAlignment) -> Self {
+        Self { pos, alignment }
+    }
+
+    #[inline]
+    pub fn pos(&self) -> IVec2 {
+        self.pos
+    }
+
+    #[inline]
+    pub fn alignment(&self) -> WallAlignment {
+        self.alignment
+    }
+
+    /// Whether moving in the direction crosses the wall, rather than moving along it.
+    #[inline]
+    pub fn is_crossed_by(&self, direction: Direction) -> bool {
+        match self.alignment {
+            WallAlignment::Up => matches!(direction, Direction::Up | Direction::Down),
+            WallAlignment::Right => matches!(direction, Direction::Left | Direction::Right),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Positioning {
     Collectible(Collectible),
@@ -57,6 +145,29 @@ impl Positioning {
         }
     }
 
+    pub fn get(entity: EntityRef) -> Option<Self> {
+        if let Some(&collectible) = entity.get::<Collectible>() {
+            Some(Positioning::Collectible(collectible))
+        } else if let Some(&floor) = entity.get::<Floor>() {
+            Some(Positioning::Floor(floor))
+        } else if let Some(&object) = entity.get::<Object>() {
+            Some(Positioning::Object(object))
+        } else {
+            entity.get::<Wall>().map(|&wall| Positioning::Wall(wall))
+        }
+    }
+
     pub fn remove(mut entity: EntityWorldMut) -> Self {
+        if let Some(collectible) = entity.take::<Collectible>() {
+            Positioning::Collectible(collectible)
+        } else if let Some(floor) = entity.take::<Floor>() {
+            Positioning::Floor(floor)
+        } else if let Some(object) = entity.take::<Object>() {
+            Positioning::Object(object)
+        } else if let Some(wall) = entity.take::<Wall>() {
+            Positioning::Wall(wall)
+        } else {
+            panic!("Entity should have one of the positioning components")
+        }
     }
 }
@@ -1,35 +1,34 @@
 use std::mem;
 
-use super::spatial_index::SpatialIndex;
+use super::{Object, Shape};
 use crate::direction::Direction;
 use crate::{
-    components::{
-        floor::{Floor, Unwalkable},
-        object::{NeedsWalkableFloor, Object, PassesThroughWalls},
-        wall::{AlwaysPassable, Wall},
+    component::{
+        color_barrier::{self, ColorBarrier},
+        floor::{crumbling, hole::Hole, teleporter, Floor, Unwalkable},
+        object::{Carrier, NeedsWalkableFloor},
+        wall::{one_way::OneWay, Opened, Wall},
     },
-    target::Target,
-};
-use bevy::prelude::Mut;
-use bevy::{
-    ecs::{
-        entity::Entity,
-        query::{Has, With},
-        system::{Query, Res, SystemState},
-        world::World,
+    level_state::{
+        state_change::translate::Translate, FloorId, ItemId, LevelState, ObjectId, WallId,
     },
-    math::IVec2,
+    target::Target,
 };
+use bevy::math::IVec2;
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum CanMoveEntity {
     Can,
-    /// Entity that [`can_move`] is called on doesn't have [`Object`] component.
-    NotAnObject,
-    BumpedIntoWall(Entity),
-    BumpedIntoObject(Entity),
+    BumpedIntoWall(WallId),
+    BumpedIntoObject(ObjectId),
     NoFloor,
-    UnwalkableFloor(Entity),
+    UnwalkableFloor(FloorId),
+    /// Object would enter a teleporter whose partner is occupied.
+    TeleporterExitOccupied(FloorId),
+    /// Wall is a [`ColorBarrier`] of a group the object doesn't belong to.
+    WrongColorWall(WallId),
+    /// Floor is a [`ColorBarrier`] of a group the object doesn't belong to.
+    WrongColorFloor(FloorId),
 }
 
 impl CanMoveEntity {
@@ -39,63 +38,228 @@ impl CanMoveEntity {
     }
 }
 
-pub fn can_move_entity(world: &World, entity: Entity, direction: Direction) -> CanMoveEntity {
-    let (spatial_index, mut object_query, mut wall_query, mut floor_query) = (
-        world.resource::<SpatialIndex>(),
-        world
-            .try_query::<(&Object, Has<NeedsWalkableFloor>, Has<PassesThroughWalls>)>()
-            .unwrap(),
-        world
-            .try_query_filtered::<Has<AlwaysPassable>, With<Wall>>()
-            .unwrap(),
-        world
-            .try_query_filtered::<Has<Unwalkable>, With<Floor>>()
-            .unwrap(),
-    );
+/// Checks every cell of the object's [`Shape`]. Cells of the object itself don't block it.
+pub fn can_move_entity(
+    level_state: &LevelState,
+    object: ObjectId,
+    direction: Direction,
+) -> CanMoveEntity {
+    let pos = object_pos(level_state, object);
+    can_move_entity_from(level_state, object, pos, direction)
+}
 
-    let Ok((object, needs_walkable_floor, passes_through_walls)) = object_query.get(world, entity)
-    else {
-        return CanMoveEntity::NotAnObject;
+/// Same as [`can_move_entity`], but as if the object was at the position.
+/// Used to look ahead without moving the object.
+pub fn can_move_entity_from(
+    level_state: &LevelState,
+    object: ObjectId,
+    pos: IVec2,
+    direction: Direction,
+) -> CanMoveEntity {
+    let (world, spatial_index) = (level_state.world(), level_state.spatial_index());
+    let cells = cells_at(level_state, object, pos);
+
+    for &cell in &cells {
+        let Some(wall) = spatial_index.get_wall(cell, direction) else {
+            continue;
+        };
+        if wall_blocks(level_state, wall, object, direction) {
+            return if world.entity(wall.0).contains::<ColorBarrier>() {
+                CanMoveEntity::WrongColorWall(wall)
+            } else {
+                CanMoveEntity::BumpedIntoWall(wall)
+            };
+        }
+    }
+
+    let layer = match landing_layer_from(level_state, object, pos, direction) {
+        Ok(layer) => layer,
+        Err(other_object) => return CanMoveEntity::BumpedIntoObject(other_object),
     };
 
-    if !passes_through_walls {
-        let wall = spatial_index.get_wall(object.pos, direction);
-        if let Some(wall_entity) = wall {
-            let always_passable = wall_query
-                .get(world, wall_entity)
-                .expect("`SpatialIndex` should return walls on wall request");
+    let needs_walkable_floor = world
+        .get::<NeedsWalkableFloor>(object.0)
+        .is_some_and(|needs| needs.0);
+
+    for &cell in &cells {
+        let floor = spatial_index.get_floor(direction + cell);
+
+        // Objects on top of carriers don't touch the floor
+        if layer > 0 {
+            break;
+        }
+
+        if needs_walkable_floor {
+            let Some(floor) = floor else {
+                return CanMoveEntity::NoFloor;
+            };
 
-            if !always_passable {
-                return CanMoveEntity::BumpedIntoWall(wall_entity);
+            let floor_entity = world.entity(floor.0);
+            if floor_entity.contains::<Unwalkable>() || floor_entity.contains::<Hole>() {
+                return CanMoveEntity::UnwalkableFloor(floor);
+            }
+        }
+
+        if let Some(floor) = floor {
+            if color_barrier::blocks(level_state, ItemId::Floor(floor), object) {
+                return CanMoveEntity::WrongColorFloor(floor);
             }
         }
     }
 
-    let other_object = spatial_index.get_object(direction + object.pos);
-    if let Some(other_object_entity) = other_object {
-        return CanMoveEntity::BumpedIntoObject(other_object_entity);
+    // Only the object's position enters teleporters
+    if let Some(floor) = spatial_index
+        .get_floor(direction + pos)
+        .filter(|_| layer == 0)
+    {
+        if let Some(exit) = teleporter::partner(level_state, floor) {
+            let exit_pos = world
+                .get::<Floor>(exit.0)
+                .expect("`teleporter::partner` should return floor")
+                .pos();
+            if !fits(level_state, object, exit_pos) {
+                return CanMoveEntity::TeleporterExitOccupied(floor);
+            }
+        }
     }
 
-    if needs_walkable_floor {
-        let Some(floor_entity) = spatial_index.get_floor(direction + object.pos) else {
-            return CanMoveEntity::NoFloor;
-        };
+    CanMoveEntity::Can
+}
 
-        let unwalkable = floor_query
-            .get(world, floor_entity)
-            .expect("`SpatialIndex` should return floor on floor request");
+/// Layer the object ends up on after moving in the direction, or the ground object of the stack
+/// it bumps into. Single cell objects land on top of a [`Carrier`] that has nothing on it.
+pub fn landing_layer(
+    level_state: &LevelState,
+    object: ObjectId,
+    direction: Direction,
+) -> Result<u32, ObjectId> {
+    let pos = object_pos(level_state, object);
+    landing_layer_from(level_state, object, pos, direction)
+}
 
-        if unwalkable {
-            return CanMoveEntity::UnwalkableFloor(floor_entity);
+fn landing_layer_from(
+    level_state: &LevelState,
+    object: ObjectId,
+    pos: IVec2,
+    direction: Direction,
+) -> Result<u32, ObjectId> {
+    let world = level_state.world();
+    let cells = cells_at(level_state, object, pos);
+    let can_land = cells.len() == 1 && !world.entity(object.0).contains::<Carrier>();
+
+    let mut landing = 0;
+    for cell in cells {
+        let stack = level_state
+            .spatial_index()
+            .get_stack(direction + cell)
+            .into_iter()
+            .take_while(|&other| other != object)
+            .collect::<Vec<_>>();
+        let (Some(&bottom), Some(&top)) = (stack.first(), stack.last()) else {
+            continue;
+        };
+
+        if can_land && world.entity(top.0).contains::<Carrier>() {
+            landing = stack.len() as u32;
+        } else {
+            return Err(bottom);
         }
     }
 
-    CanMoveEntity::Can
+    Ok(landing)
+}
+
+/// Objects directly on top of the object.
+pub fn riders(level_state: &LevelState, object: ObjectId) -> Vec<ObjectId> {
+    let layer = object_layer(level_state, object);
+    let mut riders = object_cells(level_state, object)
+        .into_iter()
+        .filter_map(|cell| level_state.spatial_index().get_object_at(cell, layer + 1))
+        .collect::<Vec<_>>();
+    riders.dedup();
+    riders
+}
+
+/// Whether the wall can be crossed in the direction.
+pub fn is_wall_passable(level_state: &LevelState, wall: WallId, direction: Direction) -> bool {
+    let wall = level_state.world().entity(wall.0);
+    let opened = wall.get::<Opened>().is_some_and(|opened| opened.0);
+    let one_way = wall.get::<OneWay>().is_some_and(|one_way| {
+        debug_assert!(wall.get::<Wall>().unwrap().is_crossed_by(one_way.0));
+        one_way.0 == direction
+    });
+    opened || one_way
+}
+
+/// Whether the wall stops the object crossing it in the direction.
+/// [`ColorBarrier`] walls stop objects of other groups, other walls stop everything unless passable.
+pub fn wall_blocks(
+    level_state: &LevelState,
+    wall: WallId,
+    object: ObjectId,
+    direction: Direction,
+) -> bool {
+    if level_state
+        .world()
+        .entity(wall.0)
+        .contains::<ColorBarrier>()
+    {
+        color_barrier::blocks(level_state, ItemId::Wall(wall), object)
+    } else {
+        !is_wall_passable(level_state, wall, direction)
+    }
+}
+
+/// Cells that the object occupies.
+pub fn object_cells(level_state: &LevelState, object: ObjectId) -> Vec<IVec2> {
+    let entity = level_state.world().entity(object.0);
+    entity
+        .get::<Object>()
+        .expect("`ObjectId` should point to an entity with `Object` component")
+        .cells(entity.get::<Shape>())
+}
+
+/// Cells that the object would occupy if it was at the position.
+pub fn cells_at(level_state: &LevelState, object: ObjectId, pos: IVec2) -> Vec<IVec2> {
+    let offset = pos - object_pos(level_state, object);
+    object_cells(level_state, object)
+        .into_iter()
+        .map(|cell| cell + offset)
+        .collect()
+}
+
+/// Whether every cell of the object would be free, or occupied by the object itself,
+/// if the object was at the position.
+pub fn fits(level_state: &LevelState, object: ObjectId, pos: IVec2) -> bool {
+    cells_at(level_state, object, pos).into_iter().all(|cell| {
+        level_state
+            .spatial_index()
+            .get_object(cell)
+            .is_none_or(|occupant| occupant == object)
+    })
+}
+
+#[inline]
+pub fn object_layer(level_state: &LevelState, object: ObjectId) -> u32 {
+    level_state
+        .world()
+        .get::<Object>(object.0)
+        .expect("`ObjectId` should point to an entity with `Object` component")
+        .layer
+}
+
+#[inline]
+pub fn object_pos(level_state: &LevelState, object: ObjectId) -> IVec2 {
+    level_state
+        .world()
+        .get::<Object>(object.0)
+        .expect("`ObjectId` should point to an entity with `Object` component")
+        .pos
 }
 
 pub struct Bumped {
-    pub initiator: Entity,
-    pub into: Entity,
+    pub initiator: ObjectId,
+    pub into: ItemId,
 }
 
 pub enum CanMove {
@@ -103,21 +267,21 @@ pub enum CanMove {
     /// Does not mean there is a free cell in front of the objects,
     /// so can't call [`translate`] on each individual object. Sholud call [`move_target`] instead.
     Can,
-    /// Vec of entities that can't move because there is no floor
-    NoFloor(Vec<Entity>),
-    /// First Vec is entities that can't move because they bumped into unwalkable floor.
-    /// Second Vec is entities that can't move because there is no floor.
-    UnwalkableFloor(Vec<Bumped>, Vec<Entity>),
+    /// Vec of objects that can't move because there is no floor
+    NoFloor(Vec<ObjectId>),
+    /// First Vec is objects that can't move because they bumped into unwalkable floor.
+    /// Second Vec is objects that can't move because there is no floor.
+    UnwalkableFloor(Vec<Bumped>, Vec<ObjectId>),
     /// Bumbed into, where `into` is not a member of the target.
     BumpedInto(Vec<Bumped>),
 }
 
 impl CanMove {
-    fn add_no_floor(&mut self, entity: Entity) {
+    fn add_no_floor(&mut self, object: ObjectId) {
         match self {
-            CanMove::Can => *self = CanMove::NoFloor(vec![entity]),
-            CanMove::NoFloor(entities) => entities.push(entity),
-            CanMove::UnwalkableFloor(_, entities) => entities.push(entity),
+            CanMove::Can => *self = CanMove::NoFloor(vec![object]),
+            CanMove::NoFloor(objects) => objects.push(object),
+            CanMove::UnwalkableFloor(_, objects) => objects.push(object),
             CanMove::BumpedInto(_) => (),
         }
     }
@@ -125,9 +289,9 @@ impl CanMove {
     fn add_unwalkable_floor(&mut self, bumped: Bumped) {
         match self {
             CanMove::Can => *self = CanMove::UnwalkableFloor(vec![bumped], vec![]),
-            CanMove::NoFloor(entities) => {
-                let entities_vec = mem::take(entities);
-                *self = CanMove::UnwalkableFloor(vec![bumped], entities_vec);
+            CanMove::NoFloor(objects) => {
+                let objects_vec = mem::take(objects);
+                *self = CanMove::UnwalkableFloor(vec![bumped], objects_vec);
             }
             CanMove::UnwalkableFloor(bumpeds, _) => {
                 bumpeds.push(bumped);
@@ -144,119 +308,157 @@ impl CanMove {
     }
 }
 
-pub fn can_move(world: &World, target: Target, direction: Direction) -> CanMove {
-    let objects = target.fitting_objects(world);
+pub fn can_move(level_state: &LevelState, target: &Target, direction: Direction) -> CanMove {
+    let objects = target.fitting_objects(level_state);
 
     let mut can_move = CanMove::Can;
 
     for object in objects {
-        let object_can_move = can_move_entity(world, object, direction);
+        let object_can_move = can_move_entity(level_state, object, direction);
 
         match object_can_move {
             CanMoveEntity::Can => continue,
-            CanMoveEntity::BumpedIntoObject(entity) => {
-                if !target.entity_matches(world, entity) {
+            CanMoveEntity::BumpedIntoObject(other) => {
+                if !target.object_matches(level_state, other) {
                     can_move.add_bumped_into(Bumped {
                         initiator: object,
-                        into: entity,
+                        into: ItemId::Object(other),
                     });
                 }
             }
-            CanMoveEntity::BumpedIntoWall(entity) => {
+            CanMoveEntity::BumpedIntoWall(wall) => {
                 can_move.add_bumped_into(Bumped {
                     initiator: object,
-                    into: entity,
+                    into: ItemId::Wall(wall),
                 });
             }
             CanMoveEntity::NoFloor => {
                 can_move.add_no_floor(object);
             }
-            CanMoveEntity::UnwalkableFloor(entity) => {
+            CanMoveEntity::UnwalkableFloor(floor) => {
                 can_move.add_unwalkable_floor(Bumped {
                     initiator: object,
-                    into: entity,
+                    into: ItemId::Floor(floor),
+                });
+            }
+            CanMoveEntity::WrongColorWall(wall) => {
+                can_move.add_bumped_into(Bumped {
+                    initiator: object,
+                    into: ItemId::Wall(wall),
+                });
+            }
+            CanMoveEntity::WrongColorFloor(floor) => {
+                can_move.add_unwalkable_floor(Bumped {
+                    initiator: object,
+                    into: ItemId::Floor(floor),
+                });
+            }
+            CanMoveEntity::TeleporterExitOccupied(floor) => {
+                can_move.add_bumped_into(Bumped {
+                    initiator: object,
+                    into: ItemId::Floor(floor),
                 });
             }
-            CanMoveEntity::NotAnObject => unreachable!(),
         }
     }
 
     can_move
 }
 
-/// CORRECTNESS: Should not be called if there is another object on the cell this object is trying to move to.
-pub fn translate(world: &mut World, entity: Entity, direction: Direction) {
-    world.resource_scope(|world, mut spatial_index: Mut<SpatialIndex>| {
-        let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
-            return;
-        };
-        let Some(mut object) = entity_mut.get_mut::<Object>() else {
-            return;
-        };
-
-        let removed = spatial_index.objects.remove(&*object);
-        debug_assert_eq!(removed, Some(entity));
+/// Moves the object together with everything on top of it.
+///
+/// CORRECTNESS: Should not be called if there is another object on the cells this object is trying to move to.
+pub fn translate(level_state: &mut LevelState, object: ObjectId, direction: Direction) {
+    let layer = object_layer(level_state, object);
+    let landing = landing_layer(level_state, object, direction)
+        .expect("Cells the object moves to should be free");
+    let cells = object_cells(level_state, object);
+
+    carry(
+        level_state,
+        object,
+        direction.into(),
+        landing as i32 - layer as i32,
+    );
 
-        object.pos = direction + object.pos;
+    if layer == 0 {
+        departed(level_state, object, cells);
+    }
+}
 
-        let replaced = spatial_index.objects.insert(*object, entity);
-        debug_assert!(replaced.is_none());
-    });
+/// Notifies floors on the cells that the object doesn't occupy anymore.
+pub fn departed(level_state: &mut LevelState, object: ObjectId, previous_cells: Vec<IVec2>) {
+    let cells = object_cells(level_state, object);
+    for cell in previous_cells {
+        if !cells.contains(&cell) {
+            crumbling::departed(level_state, cell);
+        }
+    }
 }
 
-fn amount_of_neighbors_in_direction(
-    pos: IVec2,
-    direction: Direction,
-    spatial_index: &SpatialIndex,
-) -> i32 {
-    let mut result = 0;
-    let mut current_pos = direction + pos;
-    while spatial_index.get_object(pos).is_some() {
-        result += 1;
-        current_pos = direction + current_pos;
+/// Moves the object and everything on top of it by the offset and `layers` up or down the stacks.
+///
+/// CORRECTNESS: Cells the objects move to should be free.
+pub fn carry(level_state: &mut LevelState, object: ObjectId, offset: IVec2, layers: i32) {
+    let riders = riders(level_state, object);
+
+    level_state.state_change(
+        Translate {
+            object,
+            offset,
+            layers,
+        }
+        .into(),
+    );
+
+    for rider in riders {
+        carry(level_state, rider, offset, layers);
     }
-    result
 }
 
+/// Whether any of the objects is in front of the object, under the layer it would land on.
 fn has_neighbor_in_direction(
-    pos: IVec2,
+    level_state: &LevelState,
+    object: ObjectId,
     direction: Direction,
-    spatial_index: &SpatialIndex,
+    objects: &[ObjectId],
 ) -> bool {
-    spatial_index.get_object(direction + pos).is_some()
+    object_cells(level_state, object).into_iter().any(|cell| {
+        level_state
+            .spatial_index()
+            .get_stack(direction + cell)
+            .into_iter()
+            .take_while(|&other| other != object)
+            .any(|other| objects.contains(&other))
+    })
 }
 
-/// CORRECTNESS: `can_move` with the same input arguments should not return `CanMove::BumpedInto`
-pub fn move_target(world: &mut World, target: Target, direction: Direction) {
-    let objects = target.fitting_objects(world);
-
-    // n * log(n)
-    // objects.sort_unstable_by_key(|&entity| {
-    //     let pos = world.entity(entity).get::<Object>().unwrap().pos;
-    //     amount_of_neighbors_in_direction(pos, direction, spatial_index)
-    // });
-    // for object in objects {
-    //     translate(world, object, direction);
-    // }
+/// Returns moved objects, the ones in the front first. Objects on top of them are moved along,
+/// but aren't returned.
+///
+/// CORRECTNESS: `can_move` with the same input arguments should not return `CanMove::BumpedInto`.
+/// The target should not contain objects on top of its members.
+pub fn move_target(
+    level_state: &mut LevelState,
+    target: &Target,
+    direction: Direction,
+) -> Vec<ObjectId> {
+    let mut objects = target.fitting_objects(level_state);
+    let mut moved = Vec::with_capacity(objects.len());
 
     // n * n worst case, but n is small + overwelmengly likely it will be way faster
-    let mut objects = objects.into_iter().map(Some).collect::<Vec<_>>();
-    let mut should_iterate = true;
-    while should_iterate {
-        should_iterate = false;
-        for object_option in objects.iter_mut() {
-            if let Some(object_entity) = object_option.as_mut().copied() {
-                let entity = world.entity(object_entity);
-                let object = entity.get::<Object>().unwrap();
-                let spatial_index = world.resource::<SpatialIndex>();
-
-                if has_neighbor_in_direction(object.pos, direction, spatial_index) {
-                    should_iterate = true;
-                } else {
-                    translate(world, object_entity, direction);
-                    *object_option = None;
-                }
+    while !objects.is_empty() {
+        let mut i = 0;
+        while i < objects.len() {
+            if has_neighbor_in_direction(level_state, objects[i], direction, &objects) {
+                i += 1;
+            } else {
+                let object = objects.remove(i);
+                translate(level_state, object, direction);
+                moved.push(object);
             }
         }
     }
+
+    moved
 }
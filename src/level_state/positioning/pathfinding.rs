@@ -0,0 +1,177 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use super::movement::{can_move, can_move_entity_from, object_pos, CanMove, CanMoveEntity};
+use crate::{
+    component::object::Pushable,
+    direction::Direction,
+    level_state::{LevelState, ObjectId},
+    target::Target,
+};
+use bevy::{math::IVec2, platform_support::collections::HashMap};
+
+/// Upper bound on visited positions, so objects that don't need floor can't search forever.
+const MAX_VISITED: usize = 4096;
+
+/// Pushes cost more than plain steps, so objects are only pushed when it saves a detour.
+const PUSH_COST: u32 = 4;
+
+/// Shortest sequence of steps that brings the object's position to the cell, moving only where
+/// [`can_move_entity_from`] allows. Never pushes anything.
+///
+/// Only the board as it is now is taken into account, so effects that carry the object further,
+/// like ice or teleporters, are not predicted.
+pub fn find_path(level_state: &LevelState, object: ObjectId, to: IVec2) -> Option<Vec<Direction>> {
+    search(level_state, object, to, false)
+}
+
+/// Same as [`find_path`], but the object can also push a single [`Pushable`] object, with
+/// whatever is glued to it, that can move as it is now. Pushed objects are assumed to stay in
+/// place.
+pub fn find_pushing_path(
+    level_state: &LevelState,
+    object: ObjectId,
+    to: IVec2,
+) -> Option<Vec<Direction>> {
+    search(level_state, object, to, true)
+}
+
+/// A*, the cheapest path is the shortest one without pushes.
+fn search(
+    level_state: &LevelState,
+    object: ObjectId,
+    to: IVec2,
+    allow_pushing: bool,
+) -> Option<Vec<Direction>> {
+    let from = object_pos(level_state, object);
+    let heuristic = |pos: IVec2| (to - pos).abs().element_sum() as u32;
+
+    let mut came_from = HashMap::<IVec2, (IVec2, Direction)>::default();
+    let mut costs = HashMap::<IVec2, u32>::default();
+    // Insertion order breaks ties, so the same board always gives the same path
+    let mut positions = vec![from];
+    let mut open = BinaryHeap::from([Reverse((heuristic(from), 0))]);
+    costs.insert(from, 0);
+
+    while let Some(Reverse((estimate, index))) = open.pop() {
+        let pos = positions[index];
+        let cost = costs[&pos];
+        if estimate > cost + heuristic(pos) {
+            // Already reached more cheaply
+            continue;
+        }
+
+        if pos == to {
+            let mut path = Vec::new();
+            let mut pos = pos;
+            while pos != from {
+                let (previous, direction) = came_from[&pos];
+                path.push(direction);
+                pos = previous;
+            }
+            path.reverse();
+            return Some(path);
+        }
+
+        if costs.len() >= MAX_VISITED {
+            break;
+        }
+
+        for direction in Direction::ALL {
+            let Some(step_cost) = step_cost(level_state, object, pos, direction, allow_pushing)
+            else {
+                continue;
+            };
+
+            let next = direction + pos;
+            let next_cost = cost + step_cost;
+            if costs.get(&next).is_some_and(|&known| known <= next_cost) {
+                continue;
+            }
+
+            costs.insert(next, next_cost);
+            came_from.insert(next, (pos, direction));
+            positions.push(next);
+            open.push(Reverse((next_cost + heuristic(next), positions.len() - 1)));
+        }
+    }
+
+    None
+}
+
+fn step_cost(
+    level_state: &LevelState,
+    object: ObjectId,
+    pos: IVec2,
+    direction: Direction,
+    allow_pushing: bool,
+) -> Option<u32> {
+    match can_move_entity_from(level_state, object, pos, direction) {
+        CanMoveEntity::Can => Some(1),
+        CanMoveEntity::BumpedIntoObject(other)
+            if allow_pushing
+                && level_state.world().entity(other.0).contains::<Pushable>()
+                && matches!(
+                    can_move(level_state, &Target::Glued(other), direction),
+                    CanMove::Can
+                ) =>
+        {
+            Some(PUSH_COST)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        component::{
+            object::NeedsWalkableFloor,
+            wall::{Wall, WallAlignment},
+        },
+        level_state::{
+            positioning::{Floor, Object},
+            testing,
+        },
+    };
+
+    fn walker(level_state: &mut LevelState) -> ObjectId {
+        ObjectId(level_state.spawn((Object::new(IVec2::ZERO), NeedsWalkableFloor::default())))
+    }
+
+    #[test]
+    fn path_goes_around_walls() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        for x in 0..3 {
+            for y in 0..2 {
+                level_state.spawn(Floor::new(IVec2::new(x, y)));
+            }
+        }
+        level_state.spawn(Wall::new(IVec2::ZERO, WallAlignment::Right));
+        let object = walker(&mut level_state);
+
+        let path = find_path(&level_state, object, IVec2::new(2, 0)).unwrap();
+        assert_eq!(path.len(), 4);
+        assert_eq!(path[0], Direction::Up);
+        assert_eq!(find_path(&level_state, object, IVec2::new(5, 5)), None);
+    }
+
+    #[test]
+    fn pushing_path_pushes_through_the_only_way() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        for x in 0..4 {
+            level_state.spawn(Floor::new(IVec2::new(x, 0)));
+        }
+        level_state.spawn((Object::new(IVec2::X), Pushable));
+        let object = walker(&mut level_state);
+        let to = IVec2::new(2, 0);
+
+        assert_eq!(find_path(&level_state, object, to), None);
+        assert_eq!(
+            find_pushing_path(&level_state, object, to),
+            Some(vec![Direction::Right, Direction::Right])
+        );
+    }
+}
@@ -1,6 +1,7 @@
-use super::{Collectible, Floor, Object, Positioning, Wall, WallAlignment};
+use super::{Collectible, Floor, Object, Positioning, Shape, Wall, WallAlignment};
 use crate::{direction::Direction, level_state::{CollectibleId, FloorId, ObjectId, WallId}};
-use bevy::{ecs::entity::{self, Entity}, math::IVec2, platform_support::collections::HashMap};
+use bevy::{ecs::{entity::{self, Entity}, world::EntityRef}, math::IVec2, platform_support::collections::HashMap};
+#[derive(Default)]
 pub struct SpatialIndex {
     collectibles: HashMap<Collectible, CollectibleId>,
     floor: HashMap<Floor, FloorId>,
@@ -17,8 +18,27 @@ impl SpatialIndex {
         self.floor.get(&Floor { pos }).copied()
     }
 
+    /// Object on the ground of the cell.
     pub fn get_object(&self, pos: IVec2) -> Option<ObjectId> {
-        self.objects.get(&Object { pos }).copied()
+        self.get_object_at(pos, 0)
+    }
+
+    pub fn get_object_at(&self, pos: IVec2, layer: u32) -> Option<ObjectId> {
+        self.objects.get(&Object { pos, layer }).copied()
+    }
+
+    /// Objects on the cell, from the ground up.
+    pub fn get_stack(&self, pos: IVec2) -> Vec<ObjectId> {
+        (0..)
+            .map_while(|layer| self.get_object_at(pos, layer))
+            .collect()
+    }
+
+    /// Object on the top of the stack on the cell.
+    pub fn get_top(&self, pos: IVec2) -> Option<(ObjectId, u32)> {
+        let stack = self.get_stack(pos);
+        let layer = stack.len().checked_sub(1)?;
+        Some((stack[layer], layer as u32))
     }
 
     #[rustfmt::skip]
@@ -31,24 +51,32 @@ impl SpatialIndex {
         }).copied()
     }
 
-    pub fn swap_objects(&mut self, pos1: IVec2, pos2: IVec2) {
-        let object1 = Object { pos: pos1 };
-        let object2 = Object { pos: pos2 };
-
-        let objects = self.objects.get_many_mut([&object1, &object2]);
-        match objects {
-            [Some(o1), Some(o2)] => {
-                std::mem::swap(o1, o2);
-            }
-            [Some(_), None] => {
-                let o1 = self.objects.remove(&object1);
-                self.objects.insert(object2, o1.unwrap());
+    /// Adds the item, with every cell of its [`Shape`] if it is an object.
+    pub fn spawn_entity(&mut self, entity: EntityRef) {
+        match Positioning::get(entity) {
+            Some(Positioning::Object(object)) => {
+                for pos in object.cells(entity.get::<Shape>()) {
+                    let cell = Object { pos, ..object };
+                    let replaced = self.objects.insert(cell, ObjectId(entity.id()));
+                    debug_assert!(replaced.is_none());
+                }
             }
-            [None, Some(_)] => {
-                let o2 = self.objects.remove(&object2);
-                self.objects.insert(object1, o2.unwrap());
+            Some(positioning) => self.spawn(positioning, entity.id()),
+            None => (),
+        }
+    }
+
+    /// Removes the item, with every cell of its [`Shape`] if it is an object.
+    pub fn despawn_entity(&mut self, entity: EntityRef) {
+        match Positioning::get(entity) {
+            Some(Positioning::Object(object)) => {
+                for pos in object.cells(entity.get::<Shape>()) {
+                    let removed = self.objects.remove(&Object { pos, ..object });
+                    debug_assert_eq!(removed, Some(ObjectId(entity.id())));
+                }
             }
-            _ => (),
+            Some(positioning) => self.despawn(positioning),
+            None => (),
         }
     }
 
@@ -1,8 +1,20 @@
 use super::LevelState;
 
+pub mod burn_fuse;
+pub mod change_keys;
+pub mod crack;
+pub mod crumble;
 pub mod destroy;
+pub mod set_completed;
+pub mod set_facing;
+pub mod set_gate_state;
+pub mod set_glued;
+pub mod set_opened;
+pub mod set_patrol_step;
 pub mod spawn;
-pub mod swap;
+pub mod switch_controller;
+pub mod teleport;
+pub mod translate;
 
 pub trait StateChange: Into<StateChangeEnum> {
     type Undo: Undo<Self>;
@@ -15,35 +27,87 @@ pub trait Undo<T: StateChange>: Into<UndoEnum> {
 }
 
 pub enum StateChangeEnum {
+    BurnFuse(burn_fuse::BurnFuse),
+    ChangeKeys(change_keys::ChangeKeys),
+    Crack(crack::Crack),
+    Crumble(crumble::Crumble),
     Destroy(destroy::Destroy),
+    SetCompleted(set_completed::SetCompleted),
+    SetFacing(set_facing::SetFacing),
+    SetGateState(set_gate_state::SetGateState),
+    SetGlued(set_glued::SetGlued),
+    SetOpened(set_opened::SetOpened),
+    SetPatrolStep(set_patrol_step::SetPatrolStep),
     Spawn(spawn::Spawn),
-    Swap(swap::Swap),
+    SwitchController(switch_controller::SwitchController),
+    Teleport(teleport::Teleport),
+    Translate(translate::Translate),
 }
 
 impl StateChangeEnum {
     pub fn apply(self, level_state: &mut LevelState) -> UndoEnum {
         match self {
+            StateChangeEnum::BurnFuse(burn_fuse) => burn_fuse.apply(level_state).into(),
+            StateChangeEnum::ChangeKeys(change_keys) => change_keys.apply(level_state).into(),
+            StateChangeEnum::Crack(crack) => crack.apply(level_state).into(),
+            StateChangeEnum::Crumble(crumble) => crumble.apply(level_state).into(),
             StateChangeEnum::Destroy(destroy) => destroy.apply(level_state).into(),
+            StateChangeEnum::SetCompleted(set_completed) => set_completed.apply(level_state).into(),
+            StateChangeEnum::SetFacing(set_facing) => set_facing.apply(level_state).into(),
+            StateChangeEnum::SetGateState(set_gate_state) => {
+                set_gate_state.apply(level_state).into()
+            }
+            StateChangeEnum::SetGlued(set_glued) => set_glued.apply(level_state).into(),
+            StateChangeEnum::SetOpened(set_opened) => set_opened.apply(level_state).into(),
+            StateChangeEnum::SetPatrolStep(set_patrol_step) => {
+                set_patrol_step.apply(level_state).into()
+            }
             StateChangeEnum::Spawn(spawn) => spawn.apply(level_state).into(),
-            StateChangeEnum::Swap(swap) => swap.apply(level_state).into(),
+            StateChangeEnum::SwitchController(switch) => switch.apply(level_state).into(),
+            StateChangeEnum::Teleport(teleport) => teleport.apply(level_state).into(),
+            StateChangeEnum::Translate(translate) => translate.apply(level_state).into(),
         }
     }
 }
 
 pub enum UndoEnum {
     NextBatch,
+    BurnFuse(<burn_fuse::BurnFuse as StateChange>::Undo),
+    ChangeKeys(<change_keys::ChangeKeys as StateChange>::Undo),
+    Crack(<crack::Crack as StateChange>::Undo),
+    Crumble(<crumble::Crumble as StateChange>::Undo),
     Destroy(<destroy::Destroy as StateChange>::Undo),
+    SetCompleted(<set_completed::SetCompleted as StateChange>::Undo),
+    SetFacing(<set_facing::SetFacing as StateChange>::Undo),
+    SetGateState(<set_gate_state::SetGateState as StateChange>::Undo),
+    SetGlued(<set_glued::SetGlued as StateChange>::Undo),
+    SetOpened(<set_opened::SetOpened as StateChange>::Undo),
+    SetPatrolStep(<set_patrol_step::SetPatrolStep as StateChange>::Undo),
     Spawn(<spawn::Spawn as StateChange>::Undo),
-    Swap(<swap::Swap as StateChange>::Undo),
+    SwitchController(<switch_controller::SwitchController as StateChange>::Undo),
+    Teleport(<teleport::Teleport as StateChange>::Undo),
+    Translate(<translate::Translate as StateChange>::Undo),
 }
 
 impl UndoEnum {
     pub fn undo(self, level_state: &mut LevelState) {
         match self {
             UndoEnum::NextBatch => (),
+            UndoEnum::BurnFuse(burn_fuse) => burn_fuse.undo(level_state),
+            UndoEnum::ChangeKeys(change_keys) => change_keys.undo(level_state),
+            UndoEnum::Crack(crack) => crack.undo(level_state),
+            UndoEnum::Crumble(crumble) => crumble.undo(level_state),
             UndoEnum::Destroy(destroy) => destroy.undo(level_state),
+            UndoEnum::SetCompleted(set_completed) => set_completed.undo(level_state),
+            UndoEnum::SetFacing(set_facing) => set_facing.undo(level_state),
+            UndoEnum::SetGateState(set_gate_state) => set_gate_state.undo(level_state),
+            UndoEnum::SetGlued(set_glued) => set_glued.undo(level_state),
+            UndoEnum::SetOpened(set_opened) => set_opened.undo(level_state),
+            UndoEnum::SetPatrolStep(set_patrol_step) => set_patrol_step.undo(level_state),
             UndoEnum::Spawn(spawn) => spawn.undo(level_state),
-            UndoEnum::Swap(swap) => swap.undo(level_state),
+            UndoEnum::SwitchController(switch) => switch.undo(level_state),
+            UndoEnum::Teleport(teleport) => teleport.undo(level_state),
+            UndoEnum::Translate(translate) => translate.undo(level_state),
         }
     }
 }
@@ -0,0 +1,51 @@
+use super::{StateChange, StateChangeEnum, Undo, UndoEnum};
+use crate::{
+    component::bomb::Fuse,
+    level_state::{LevelState, ObjectId},
+};
+
+/// Adds `turns` to the turns left on the bomb's [`Fuse`].
+pub struct BurnFuse {
+    pub object: ObjectId,
+    pub turns: i32,
+}
+
+impl StateChange for BurnFuse {
+    type Undo = Self;
+
+    fn apply(self, level_state: &mut LevelState) -> Self::Undo {
+        let mut fuse = level_state
+            .world
+            .get_mut::<Fuse>(self.object.0)
+            .expect("Only objects with `Fuse` should burn it");
+        fuse.turns_left = fuse
+            .turns_left
+            .checked_add_signed(self.turns)
+            .expect("Burnt out fuse should not burn");
+
+        BurnFuse {
+            turns: -self.turns,
+            ..self
+        }
+    }
+}
+
+impl Undo<BurnFuse> for BurnFuse {
+    fn undo(self, level_state: &mut LevelState) {
+        self.apply(level_state);
+    }
+}
+
+impl Into<StateChangeEnum> for BurnFuse {
+    #[inline]
+    fn into(self) -> StateChangeEnum {
+        StateChangeEnum::BurnFuse(self)
+    }
+}
+
+impl Into<UndoEnum> for BurnFuse {
+    #[inline]
+    fn into(self) -> UndoEnum {
+        UndoEnum::BurnFuse(self)
+    }
+}
@@ -0,0 +1,96 @@
+use super::{StateChange, StateChangeEnum, Undo, UndoEnum};
+use crate::{
+    component::{collectible::key::Inventory, Group},
+    level_state::{LevelState, ObjectId},
+};
+
+/// Adds `amount` keys of the group to the object's [`Inventory`]. Negative amount takes them.
+///
+/// Takes at most the keys the object has, so undo gives back exactly what was taken.
+pub struct ChangeKeys {
+    pub object: ObjectId,
+    pub group: Group,
+    pub amount: i32,
+}
+
+impl StateChange for ChangeKeys {
+    type Undo = Self;
+
+    fn apply(self, level_state: &mut LevelState) -> Self::Undo {
+        let mut inventory = level_state
+            .world
+            .get_mut::<Inventory>(self.object.0)
+            .expect("Only objects with `Inventory` should carry keys");
+        let keys = inventory.keys.entry(self.group).or_default();
+        let amount = self.amount.max(-(*keys as i32));
+        *keys = keys.saturating_add_signed(amount);
+
+        ChangeKeys {
+            amount: -amount,
+            ..self
+        }
+    }
+}
+
+impl Undo<ChangeKeys> for ChangeKeys {
+    fn undo(self, level_state: &mut LevelState) {
+        self.apply(level_state);
+    }
+}
+
+impl Into<StateChangeEnum> for ChangeKeys {
+    #[inline]
+    fn into(self) -> StateChangeEnum {
+        StateChangeEnum::ChangeKeys(self)
+    }
+}
+
+impl Into<UndoEnum> for ChangeKeys {
+    #[inline]
+    fn into(self) -> UndoEnum {
+        UndoEnum::ChangeKeys(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level_state::{positioning::Object, testing};
+    use bevy::math::IVec2;
+
+    fn keys(level_state: &LevelState, object: ObjectId) -> u32 {
+        level_state
+            .world()
+            .get::<Inventory>(object.0)
+            .unwrap()
+            .keys(Group::Red)
+    }
+
+    fn change(level_state: &mut LevelState, object: ObjectId, amount: i32) {
+        level_state.next_batch(true);
+        level_state.state_change(
+            ChangeKeys {
+                object,
+                group: Group::Red,
+                amount,
+            }
+            .into(),
+        );
+    }
+
+    #[test]
+    fn taking_more_keys_than_held_takes_what_is_there() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let object = ObjectId(level_state.spawn((Object::new(IVec2::ZERO), Inventory::default())));
+
+        change(&mut level_state, object, 2);
+        change(&mut level_state, object, -3);
+        assert_eq!(keys(&level_state, object), 0);
+
+        level_state.undo_batch();
+        assert_eq!(keys(&level_state, object), 2);
+        level_state.undo_batch();
+        assert_eq!(keys(&level_state, object), 0);
+    }
+}
@@ -0,0 +1,58 @@
+use super::{StateChange, StateChangeEnum, Undo, UndoEnum};
+use crate::{
+    component::wall::breakable::Breakable,
+    event::WallCracked,
+    level_state::{LevelState, WallId},
+};
+
+/// Adds `hits` to the hit points of the [`Breakable`] wall.
+pub struct Crack {
+    pub wall: WallId,
+    pub hits: i32,
+}
+
+impl StateChange for Crack {
+    type Undo = Self;
+
+    fn apply(self, level_state: &mut LevelState) -> Self::Undo {
+        let mut breakable = level_state
+            .world
+            .get_mut::<Breakable>(self.wall.0)
+            .expect("Only `Breakable` walls should crack");
+        breakable.hit_points = breakable
+            .hit_points
+            .checked_add_signed(self.hits)
+            .expect("Broken wall should not crack");
+        let hit_points = breakable.hit_points;
+
+        level_state.world.send_event(WallCracked {
+            wall: self.wall,
+            hit_points,
+        });
+
+        Crack {
+            hits: -self.hits,
+            ..self
+        }
+    }
+}
+
+impl Undo<Crack> for Crack {
+    fn undo(self, level_state: &mut LevelState) {
+        self.apply(level_state);
+    }
+}
+
+impl Into<StateChangeEnum> for Crack {
+    #[inline]
+    fn into(self) -> StateChangeEnum {
+        StateChangeEnum::Crack(self)
+    }
+}
+
+impl Into<UndoEnum> for Crack {
+    #[inline]
+    fn into(self) -> UndoEnum {
+        UndoEnum::Crack(self)
+    }
+}
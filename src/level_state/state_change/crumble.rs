@@ -0,0 +1,51 @@
+use super::{StateChange, StateChangeEnum, Undo, UndoEnum};
+use crate::{
+    component::floor::crumbling::Crumbling,
+    level_state::{FloorId, LevelState},
+};
+
+/// Adds `departures` to the departures left of the [`Crumbling`] floor.
+pub struct Crumble {
+    pub floor: FloorId,
+    pub departures: i32,
+}
+
+impl StateChange for Crumble {
+    type Undo = Self;
+
+    fn apply(self, level_state: &mut LevelState) -> Self::Undo {
+        let mut crumbling = level_state
+            .world
+            .get_mut::<Crumbling>(self.floor.0)
+            .expect("Only `Crumbling` floors should crumble");
+        crumbling.departures_left = crumbling
+            .departures_left
+            .checked_add_signed(self.departures)
+            .expect("Broken floor should not crumble");
+
+        Crumble {
+            departures: -self.departures,
+            ..self
+        }
+    }
+}
+
+impl Undo<Crumble> for Crumble {
+    fn undo(self, level_state: &mut LevelState) {
+        self.apply(level_state);
+    }
+}
+
+impl Into<StateChangeEnum> for Crumble {
+    #[inline]
+    fn into(self) -> StateChangeEnum {
+        StateChangeEnum::Crumble(self)
+    }
+}
+
+impl Into<UndoEnum> for Crumble {
+    #[inline]
+    fn into(self) -> UndoEnum {
+        UndoEnum::Crumble(self)
+    }
+}
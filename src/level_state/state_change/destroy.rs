@@ -2,13 +2,18 @@ use super::{StateChange, StateChangeEnum, Undo, UndoEnum};
 use crate::level_state::{ItemId, LevelState};
 use bevy::ecs::{entity_disabling::Disabled, hierarchy::Children};
 
-pub struct Destroy(ItemId);
+/// Disables the item and removes it from the `SpatialIndex`, so it stops taking part in the game.
+/// Undo puts it back.
+pub struct Destroy(pub ItemId);
 
 impl StateChange for Destroy {
     type Undo = Self;
     fn apply(self, level_state: &mut LevelState) -> Self::Undo {
         let entity = self.0.entity();
 
+        let entity_ref = level_state.world.entity(entity);
+        level_state.root.spatial_index.despawn_entity(entity_ref);
+
         level_state
             .world
             .entity_mut(entity)
@@ -25,6 +30,9 @@ impl Undo<Destroy> for Destroy {
             .world
             .entity_mut(entity)
             .remove_recursive::<Children, Disabled>();
+
+        let entity_ref = level_state.world.entity(entity);
+        level_state.root.spatial_index.spawn_entity(entity_ref);
     }
 }
 
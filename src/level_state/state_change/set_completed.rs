@@ -0,0 +1,36 @@
+use super::{StateChange, StateChangeEnum, Undo, UndoEnum};
+use crate::level_state::LevelState;
+
+/// Marks the level as completed or not. Undo restores the previous state.
+pub struct SetCompleted(pub bool);
+
+impl StateChange for SetCompleted {
+    type Undo = Self;
+
+    fn apply(self, level_state: &mut LevelState) -> Self::Undo {
+        let previous = level_state.root.completed;
+        level_state.root.completed = self.0;
+
+        SetCompleted(previous)
+    }
+}
+
+impl Undo<SetCompleted> for SetCompleted {
+    fn undo(self, level_state: &mut LevelState) {
+        self.apply(level_state);
+    }
+}
+
+impl Into<StateChangeEnum> for SetCompleted {
+    #[inline]
+    fn into(self) -> StateChangeEnum {
+        StateChangeEnum::SetCompleted(self)
+    }
+}
+
+impl Into<UndoEnum> for SetCompleted {
+    #[inline]
+    fn into(self) -> UndoEnum {
+        UndoEnum::SetCompleted(self)
+    }
+}
@@ -0,0 +1,50 @@
+use super::{StateChange, StateChangeEnum, Undo, UndoEnum};
+use crate::{
+    component::object::facing::Facing,
+    direction::Direction,
+    level_state::{LevelState, ObjectId},
+};
+
+/// Turns the object to face the direction. Undo restores the previous facing.
+pub struct SetFacing {
+    pub object: ObjectId,
+    pub facing: Direction,
+}
+
+impl StateChange for SetFacing {
+    type Undo = Self;
+
+    fn apply(self, level_state: &mut LevelState) -> Self::Undo {
+        let mut object = level_state.world.entity_mut(self.object.0);
+        let previous = object
+            .get::<Facing>()
+            .copied()
+            .expect("Only objects with `Facing` should be turned");
+        object.insert(Facing(self.facing));
+
+        SetFacing {
+            object: self.object,
+            facing: previous.0,
+        }
+    }
+}
+
+impl Undo<SetFacing> for SetFacing {
+    fn undo(self, level_state: &mut LevelState) {
+        self.apply(level_state);
+    }
+}
+
+impl Into<StateChangeEnum> for SetFacing {
+    #[inline]
+    fn into(self) -> StateChangeEnum {
+        StateChangeEnum::SetFacing(self)
+    }
+}
+
+impl Into<UndoEnum> for SetFacing {
+    #[inline]
+    fn into(self) -> UndoEnum {
+        UndoEnum::SetFacing(self)
+    }
+}
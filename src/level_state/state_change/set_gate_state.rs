@@ -0,0 +1,46 @@
+use super::{StateChange, StateChangeEnum, Undo, UndoEnum};
+use crate::{
+    component::logic::{GateId, GateState},
+    level_state::LevelState,
+};
+
+/// Replaces the state of the gate. Undo restores the previous state.
+pub struct SetGateState {
+    pub gate: GateId,
+    pub state: GateState,
+}
+
+impl StateChange for SetGateState {
+    type Undo = Self;
+
+    fn apply(self, level_state: &mut LevelState) -> Self::Undo {
+        let mut gate = level_state.world.entity_mut(self.gate.0);
+        let previous = gate.take::<GateState>().unwrap_or_default();
+        gate.insert(self.state);
+
+        SetGateState {
+            gate: self.gate,
+            state: previous,
+        }
+    }
+}
+
+impl Undo<SetGateState> for SetGateState {
+    fn undo(self, level_state: &mut LevelState) {
+        self.apply(level_state);
+    }
+}
+
+impl Into<StateChangeEnum> for SetGateState {
+    #[inline]
+    fn into(self) -> StateChangeEnum {
+        StateChangeEnum::SetGateState(self)
+    }
+}
+
+impl Into<UndoEnum> for SetGateState {
+    #[inline]
+    fn into(self) -> UndoEnum {
+        UndoEnum::SetGateState(self)
+    }
+}
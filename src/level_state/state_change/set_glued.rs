@@ -0,0 +1,59 @@
+use super::{StateChange, StateChangeEnum, Undo, UndoEnum};
+use crate::{
+    component::object::glue::Glued,
+    level_state::{LevelState, ObjectId},
+};
+
+/// Glues the objects to each other or unglues them. Undo restores the previous state.
+pub struct SetGlued {
+    pub objects: (ObjectId, ObjectId),
+    pub glued: bool,
+}
+
+impl StateChange for SetGlued {
+    type Undo = Self;
+
+    fn apply(self, level_state: &mut LevelState) -> Self::Undo {
+        let (first, second) = self.objects;
+        let mut previous = false;
+
+        for (object, other) in [(first, second), (second, first)] {
+            let mut entity = level_state.world.entity_mut(object.0);
+            if !entity.contains::<Glued>() {
+                entity.insert(Glued::default());
+            }
+            let mut glued = entity.get_mut::<Glued>().unwrap();
+
+            previous = glued.0.contains(&other);
+            glued.0.retain(|&glued| glued != other);
+            if self.glued {
+                glued.0.push(other);
+            }
+        }
+
+        SetGlued {
+            objects: self.objects,
+            glued: previous,
+        }
+    }
+}
+
+impl Undo<SetGlued> for SetGlued {
+    fn undo(self, level_state: &mut LevelState) {
+        self.apply(level_state);
+    }
+}
+
+impl Into<StateChangeEnum> for SetGlued {
+    #[inline]
+    fn into(self) -> StateChangeEnum {
+        StateChangeEnum::SetGlued(self)
+    }
+}
+
+impl Into<UndoEnum> for SetGlued {
+    #[inline]
+    fn into(self) -> UndoEnum {
+        UndoEnum::SetGlued(self)
+    }
+}
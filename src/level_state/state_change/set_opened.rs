@@ -0,0 +1,46 @@
+use super::{StateChange, StateChangeEnum, Undo, UndoEnum};
+use crate::{
+    component::wall::Opened,
+    level_state::{LevelState, WallId},
+};
+
+/// Opens or closes the wall. Undo restores the previous state.
+pub struct SetOpened {
+    pub wall: WallId,
+    pub opened: bool,
+}
+
+impl StateChange for SetOpened {
+    type Undo = Self;
+
+    fn apply(self, level_state: &mut LevelState) -> Self::Undo {
+        let mut wall = level_state.world.entity_mut(self.wall.0);
+        let previous = wall.get::<Opened>().copied().unwrap_or_default();
+        wall.insert(Opened(self.opened));
+
+        SetOpened {
+            wall: self.wall,
+            opened: previous.0,
+        }
+    }
+}
+
+impl Undo<SetOpened> for SetOpened {
+    fn undo(self, level_state: &mut LevelState) {
+        self.apply(level_state);
+    }
+}
+
+impl Into<StateChangeEnum> for SetOpened {
+    #[inline]
+    fn into(self) -> StateChangeEnum {
+        StateChangeEnum::SetOpened(self)
+    }
+}
+
+impl Into<UndoEnum> for SetOpened {
+    #[inline]
+    fn into(self) -> UndoEnum {
+        UndoEnum::SetOpened(self)
+    }
+}
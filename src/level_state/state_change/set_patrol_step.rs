@@ -0,0 +1,46 @@
+use super::{StateChange, StateChangeEnum, Undo, UndoEnum};
+use crate::{
+    component::object::enemy::PatrolStep,
+    level_state::{LevelState, ObjectId},
+};
+
+/// Sets the next step of the patrolling enemy. Undo restores the previous step.
+pub struct SetPatrolStep {
+    pub object: ObjectId,
+    pub step: usize,
+}
+
+impl StateChange for SetPatrolStep {
+    type Undo = Self;
+
+    fn apply(self, level_state: &mut LevelState) -> Self::Undo {
+        let mut object = level_state.world.entity_mut(self.object.0);
+        let previous = object.get::<PatrolStep>().copied().unwrap_or_default();
+        object.insert(PatrolStep(self.step));
+
+        SetPatrolStep {
+            object: self.object,
+            step: previous.0,
+        }
+    }
+}
+
+impl Undo<SetPatrolStep> for SetPatrolStep {
+    fn undo(self, level_state: &mut LevelState) {
+        self.apply(level_state);
+    }
+}
+
+impl Into<StateChangeEnum> for SetPatrolStep {
+    #[inline]
+    fn into(self) -> StateChangeEnum {
+        StateChangeEnum::SetPatrolStep(self)
+    }
+}
+
+impl Into<UndoEnum> for SetPatrolStep {
+    #[inline]
+    fn into(self) -> UndoEnum {
+        UndoEnum::SetPatrolStep(self)
+    }
+}
@@ -4,7 +4,8 @@ use bevy::ecs::{entity::Entity, world::EntityWorldMut};
 
 pub type SpawnDescription = fn(&mut EntityWorldMut);
 
-pub struct Spawn(SpawnDescription, Positioning);
+/// Spawns an item described by the function at the position, and puts it into the `SpatialIndex`.
+pub struct Spawn(pub SpawnDescription, pub Positioning);
 pub struct SpawnUndo(Entity);
 
 impl StateChange for Spawn {
@@ -12,13 +13,19 @@ impl StateChange for Spawn {
 
     fn apply(self, level_state: &mut LevelState) -> Self::Undo {
         let entity = level_state.world.spawn_empty().id();
-        (self.0)(&mut level_state.world.entity_mut(entity));
+        let mut entity_mut = level_state.world.entity_mut(entity);
+        (self.0)(&mut entity_mut);
+        self.1.insert(entity_mut);
+        let entity_ref = level_state.world.entity(entity);
+        level_state.root.spatial_index.spawn_entity(entity_ref);
         SpawnUndo(entity)
     }
 }
 
 impl Undo<Spawn> for SpawnUndo {
     fn undo(self, level_state: &mut LevelState) {
+        let entity = level_state.world.entity(self.0);
+        level_state.root.spatial_index.despawn_entity(entity);
         level_state.world.despawn(self.0);
     }
 }
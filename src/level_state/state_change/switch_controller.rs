@@ -0,0 +1,34 @@
+use super::{StateChange, StateChangeEnum, Undo, UndoEnum};
+use crate::level_state::{LevelState, ObjectId};
+
+/// Makes the object the active controller. Undo restores the previous one.
+pub struct SwitchController(pub Option<ObjectId>);
+
+impl StateChange for SwitchController {
+    type Undo = Self;
+
+    fn apply(self, level_state: &mut LevelState) -> Self::Undo {
+        let previous = std::mem::replace(&mut level_state.root.active_controller, self.0);
+        SwitchController(previous)
+    }
+}
+
+impl Undo<SwitchController> for SwitchController {
+    fn undo(self, level_state: &mut LevelState) {
+        self.apply(level_state);
+    }
+}
+
+impl Into<StateChangeEnum> for SwitchController {
+    #[inline]
+    fn into(self) -> StateChangeEnum {
+        StateChangeEnum::SwitchController(self)
+    }
+}
+
+impl Into<UndoEnum> for SwitchController {
+    #[inline]
+    fn into(self) -> UndoEnum {
+        UndoEnum::SwitchController(self)
+    }
+}
@@ -0,0 +1,119 @@
+use super::{translate::Translate, StateChange, StateChangeEnum, Undo, UndoEnum};
+use crate::{
+    event::Teleported,
+    level_state::{
+        positioning::movement::{object_pos, riders},
+        LevelState, ObjectId,
+    },
+};
+use bevy::math::IVec2;
+
+/// Moves the object, together with everything on top of it, to a cell that isn't a neighbor.
+/// Unlike [`Translate`], sends [`Teleported`] for every moved object, so the renderer doesn't
+/// show it as a slide.
+///
+/// CORRECTNESS: The object should fit at `to`.
+pub struct Teleport {
+    pub object: ObjectId,
+    pub to: IVec2,
+}
+
+impl StateChange for Teleport {
+    type Undo = Self;
+
+    fn apply(self, level_state: &mut LevelState) -> Self::Undo {
+        let from = object_pos(level_state, self.object);
+        let offset = self.to - from;
+
+        let mut stack = vec![self.object];
+        let mut i = 0;
+        while let Some(&object) = stack.get(i) {
+            stack.extend(riders(level_state, object));
+            i += 1;
+        }
+
+        for object in stack {
+            let from = object_pos(level_state, object);
+            Translate {
+                object,
+                offset,
+                layers: 0,
+            }
+            .apply(level_state);
+
+            level_state.world.send_event(Teleported {
+                object,
+                from,
+                to: from + offset,
+            });
+        }
+
+        Teleport {
+            object: self.object,
+            to: from,
+        }
+    }
+}
+
+impl Undo<Teleport> for Teleport {
+    fn undo(self, level_state: &mut LevelState) {
+        self.apply(level_state);
+    }
+}
+
+impl Into<StateChangeEnum> for Teleport {
+    #[inline]
+    fn into(self) -> StateChangeEnum {
+        StateChangeEnum::Teleport(self)
+    }
+}
+
+impl Into<UndoEnum> for Teleport {
+    #[inline]
+    fn into(self) -> UndoEnum {
+        UndoEnum::Teleport(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        component::object::Carrier,
+        level_state::{positioning::Object, testing},
+    };
+
+    #[test]
+    fn riders_teleport_with_the_carrier() {
+        let mut world = testing::world();
+        let mut level_state = LevelState::new(&mut world);
+        let carrier = ObjectId(level_state.spawn((Object::new(IVec2::ZERO), Carrier)));
+        let rider = ObjectId(level_state.spawn(Object {
+            pos: IVec2::ZERO,
+            layer: 1,
+        }));
+        let to = IVec2::new(5, 0);
+
+        level_state.next_batch(true);
+        level_state.state_change(
+            Teleport {
+                object: carrier,
+                to,
+            }
+            .into(),
+        );
+        assert_eq!(level_state.spatial_index().get_stack(to), [carrier, rider]);
+        assert!(level_state
+            .spatial_index()
+            .get_stack(IVec2::ZERO)
+            .is_empty());
+        assert_eq!(testing::sent::<Teleported>(&level_state), 2);
+
+        level_state.undo_batch();
+        assert_eq!(
+            level_state.spatial_index().get_stack(IVec2::ZERO),
+            [carrier, rider]
+        );
+        assert!(level_state.spatial_index().get_stack(to).is_empty());
+    }
+}
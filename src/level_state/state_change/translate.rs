@@ -0,0 +1,61 @@
+use super::{StateChange, StateChangeEnum, Undo, UndoEnum};
+use crate::level_state::{positioning::Object, LevelState, ObjectId};
+use bevy::math::IVec2;
+
+/// Moves the object with every cell of its [`Shape`](crate::level_state::positioning::Shape),
+/// and `layers` up or down the stacks.
+///
+/// CORRECTNESS: Cells the object moves to should be free or occupied by the object itself.
+pub struct Translate {
+    pub object: ObjectId,
+    pub offset: IVec2,
+    pub layers: i32,
+}
+
+impl StateChange for Translate {
+    type Undo = Self;
+
+    fn apply(self, level_state: &mut LevelState) -> Self::Undo {
+        let entity = level_state.world.entity(self.object.0);
+        level_state.root.spatial_index.despawn_entity(entity);
+
+        let mut object = level_state
+            .world
+            .get_mut::<Object>(self.object.0)
+            .expect("`ObjectId` should point to an entity with `Object` component");
+        object.pos += self.offset;
+        object.layer = object
+            .layer
+            .checked_add_signed(self.layers)
+            .expect("Object can't go below the ground");
+
+        let entity = level_state.world.entity(self.object.0);
+        level_state.root.spatial_index.spawn_entity(entity);
+
+        Translate {
+            object: self.object,
+            offset: -self.offset,
+            layers: -self.layers,
+        }
+    }
+}
+
+impl Undo<Translate> for Translate {
+    fn undo(self, level_state: &mut LevelState) {
+        self.apply(level_state);
+    }
+}
+
+impl Into<StateChangeEnum> for Translate {
+    #[inline]
+    fn into(self) -> StateChangeEnum {
+        StateChangeEnum::Translate(self)
+    }
+}
+
+impl Into<UndoEnum> for Translate {
+    #[inline]
+    fn into(self) -> UndoEnum {
+        UndoEnum::Translate(self)
+    }
+}
@@ -0,0 +1,49 @@
+//! Levels built by hand for unit tests.
+
+use super::{LevelRoot, LevelState};
+use bevy::{
+    app::App,
+    ecs::{
+        bundle::Bundle,
+        entity::Entity,
+        event::{Event, Events},
+        world::World,
+    },
+};
+
+/// World with every component and event of the game registered.
+pub(crate) fn world() -> World {
+    let mut app = App::new();
+    app.add_plugins(crate::TrappedPlugin);
+    std::mem::take(app.world_mut())
+}
+
+/// Amount of events of the type sent so far.
+pub(crate) fn sent<E: Event>(level_state: &LevelState) -> usize {
+    level_state.world().resource::<Events<E>>().len()
+}
+
+impl<'w> LevelState<'w> {
+    /// Empty level, see [`world`].
+    pub(crate) fn new(world: &'w mut World) -> Self {
+        Self {
+            world,
+            root: LevelRoot::default(),
+        }
+    }
+
+    /// For changing components outside of the undo stack, like loading the level would.
+    pub(crate) fn world_mut(&mut self) -> &mut World {
+        self.world
+    }
+
+    /// Adds the entity to the level without recording it on the undo stack, like loading the
+    /// level would. Entities with a positioning component are put into the `SpatialIndex`.
+    pub(crate) fn spawn(&mut self, bundle: impl Bundle) -> Entity {
+        let entity = self.world.spawn(bundle).id();
+        self.root
+            .spatial_index
+            .spawn_entity(self.world.entity(entity));
+        entity
+    }
+}
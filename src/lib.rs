@@ -9,8 +9,10 @@ use bevy::app::{App, Plugin};
 mod action;
 mod component;
 mod direction;
+mod event;
 mod game_loop;
 mod level_state;
+mod target;
 
 pub struct TrappedPlugin;
 
@@ -18,6 +20,7 @@ impl Plugin for TrappedPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins((
             component::RegisterComponentsPlugin,
+            event::LevelEventsPlugin,
             game_loop::GameLoopPlugin,
         ));
     }
@@ -0,0 +1,32 @@
+use crate::{
+    component::object::glue,
+    level_state::{LevelState, ObjectId},
+};
+
+/// Set of objects that move together as a whole.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Target {
+    Object(ObjectId),
+    Objects(Vec<ObjectId>),
+    /// The object and every object [`Glued`](glue::Glued) to it.
+    Glued(ObjectId),
+}
+
+impl Target {
+    /// Objects of the target that weren't destroyed.
+    pub fn fitting_objects(&self, level_state: &LevelState) -> Vec<ObjectId> {
+        let objects = match self {
+            Target::Object(object) => vec![*object],
+            Target::Objects(objects) => objects.clone(),
+            Target::Glued(object) => glue::group(level_state, *object),
+        };
+        objects
+            .into_iter()
+            .filter(|object| !level_state.is_destroyed(object.0))
+            .collect()
+    }
+
+    pub fn object_matches(&self, level_state: &LevelState, object: ObjectId) -> bool {
+        self.fitting_objects(level_state).contains(&object)
+    }
+}